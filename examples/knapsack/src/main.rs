@@ -1,6 +1,9 @@
 use std::time::Duration;
 
-use optimum::{core::Problem, metaheuristics::neighborhood::Move};
+use optimum::{
+    core::{Constrained, Problem},
+    metaheuristics::neighborhood::Move,
+};
 use rand::thread_rng;
 
 use crate::{
@@ -58,6 +61,7 @@ fn main() {
         }
 
         evaluation = r#move.apply(&problem, evaluation);
+        debug_assert!(problem.is_feasible(evaluation.solution()));
         std::thread::sleep(Duration::from_secs(1));
         dbg!(&evaluation);
     }
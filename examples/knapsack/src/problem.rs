@@ -1,4 +1,4 @@
-use optimum::core::{Evaluation, Objective, Problem};
+use optimum::core::{Constrained, Evaluation, Objective, Problem};
 
 #[derive(Debug)]
 pub struct Knapsack {
@@ -27,6 +27,15 @@ impl Problem for Knapsack {
     }
 }
 
+impl Constrained for Knapsack {
+    /// The weight above the knapsack's capacity
+    type Violation = usize;
+
+    fn violation(&self, solution: &Self::Solution) -> Self::Violation {
+        solution.total_weight.saturating_sub(self.max_weight)
+    }
+}
+
 #[derive(Debug)]
 pub struct Item {
     pub value: usize,
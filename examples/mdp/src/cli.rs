@@ -17,8 +17,17 @@ pub struct Opts {
     pub mutants: usize,
     #[clap(long)]
     pub crossover_bias: f64,
+    #[clap(long, default_value = "1")]
+    pub populations: usize,
+    #[clap(long, default_value = "100")]
+    pub exchange_interval: usize,
+    #[clap(long, default_value = "2")]
+    pub exchange_count: usize,
     #[clap(long)]
     pub seed: usize,
+    /// Writes the trajectory of every execution to this file as CSV.
+    #[clap(long)]
+    pub trajectory: Option<PathBuf>,
 }
 
 #[derive(Debug)]
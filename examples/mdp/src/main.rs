@@ -1,6 +1,10 @@
 mod cli;
 
-use std::{fs::read_to_string, path::Path};
+use std::{
+    fs::{read_to_string, File},
+    io::BufWriter,
+    path::Path,
+};
 
 use clap::StructOpt;
 use cli::{DecoderChooser, Opts};
@@ -8,44 +12,63 @@ use mdp::{CurrentDecoder, ExperimentalDecoder, MaximumDiversity};
 use ndarray::Array2;
 use optimum::{
     analysis::batch::{Batch, BatchResult, Statistics},
-    core::{solver::IterHook, stop_criterion::IterCriterion, Problem},
+    core::{solver::hook::Record, stop_criterion::IterCriterion, Problem},
     metaheuristics::genetic::{
-        brkga::{Brkga, BrkgaHook, Params, RandomMemberBuilder},
+        brkga::{Brkga, Params, RandomMemberBuilder},
         Decoder,
     },
 };
 use rand::SeedableRng;
 
-fn main() -> std::io::Result<()> {
+fn main() -> anyhow::Result<()> {
     let opts = Opts::parse();
 
     let problem = load_input(&opts.instance)?;
 
-    let create_params = |member_size: usize| Params {
-        population_size: opts.population_size.try_into().unwrap(),
-        member_size: member_size.try_into().unwrap(),
-        elites: opts.elites,
-        mutants: opts.mutants,
-        crossover_bias: opts.crossover_bias,
+    let create_params = |member_size: usize| {
+        Params::builder(opts.population_size, member_size)
+            .elites(opts.elites)
+            .mutants(opts.mutants)
+            .crossover_bias(opts.crossover_bias)
+            .populations(opts.populations)
+            .exchange(opts.exchange_interval, opts.exchange_count)
+            .build()
     };
 
     match opts.decoder {
         DecoderChooser::New => {
             println!("Using new decoder");
             let decoder = ExperimentalDecoder::new(&problem);
-            run(decoder, create_params(problem.solution_size), opts.seed);
+            run(
+                decoder,
+                create_params(problem.solution_size)?,
+                opts.seed,
+                opts.trajectory.as_deref(),
+            )?;
         }
         DecoderChooser::Current => {
             println!("Using current decoder");
             let decoder = CurrentDecoder::new(&problem);
-            run(decoder, create_params(problem.input_size), opts.seed);
+            run(
+                decoder,
+                create_params(problem.input_size)?,
+                opts.seed,
+                opts.trajectory.as_deref(),
+            )?;
         }
     }
 
     Ok(())
 }
 
-fn run<D: Decoder<P = MaximumDiversity>>(decoder: D, params: Params, seed: usize) {
+fn run<D: Decoder<P = MaximumDiversity> + Sync>(
+    decoder: D,
+    params: Params,
+    seed: usize,
+    trajectory: Option<&Path>,
+) -> anyhow::Result<()> {
+    #[cfg(feature = "parallel")]
+    let decoder = optimum::metaheuristics::genetic::parallel::Parallel::new(decoder);
     let stop_criterion = IterCriterion::new(1000);
 
     let build_solver = |seed, exec_number| {
@@ -59,12 +82,15 @@ fn run<D: Decoder<P = MaximumDiversity>>(decoder: D, params: Params, seed: usize
         .executions(10)
         .solver(build_solver)
         .stop_criterion(stop_criterion)
-        .hook(LogHook::default())
+        .hook(Record::new())
         .build()
         .run()
         .unwrap();
 
     print_logs(&batch);
+    if let Some(path) = trajectory {
+        batch.write_csv(BufWriter::new(File::create(path)?))?;
+    }
 
     let statistics = Statistics::new(&batch);
     let best_execution = statistics.best();
@@ -74,12 +100,14 @@ fn run<D: Decoder<P = MaximumDiversity>>(decoder: D, params: Params, seed: usize
         best_execution.evaluation().value(),
         statistics.average_time().as_secs_f64(),
     );
+
+    Ok(())
 }
 
-fn print_logs(batch: &BatchResult<MaximumDiversity, LogHook>) {
+fn print_logs(batch: &BatchResult<MaximumDiversity, Record<<MaximumDiversity as Problem>::Value>>) {
     for execution in batch.executions().iter() {
-        for (idx, value) in execution.hook().values.iter().enumerate() {
-            println!("ITER {number} LOCAL_SEARCH {value}", number = idx + 1);
+        for step in execution.hook().steps() {
+            println!("ITER {} LOCAL_SEARCH {}", step.iteration, step.value);
         }
         println!(
             "EXEC {} VALUE {} TIME {}",
@@ -121,19 +149,3 @@ fn load_input(path: &Path) -> std::io::Result<MaximumDiversity> {
         input_size,
     })
 }
-
-#[derive(Debug, Default, Clone)]
-struct LogHook {
-    values: Vec<f64>,
-}
-
-impl<P: Problem> IterHook<P> for LogHook
-where
-    P::Value: Into<f64>,
-{
-    fn iterated(&mut self, new: &optimum::core::Evaluation<P>) {
-        self.values.push(new.value().into());
-    }
-}
-
-impl<D: Decoder> BrkgaHook<D> for LogHook where <D::P as Problem>::Value: Into<f64> {}
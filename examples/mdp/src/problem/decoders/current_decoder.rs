@@ -1,6 +1,5 @@
-use std::cell::RefCell;
-
 use optimum::{
+    components::scratch::Scratch,
     core::Problem,
     metaheuristics::genetic::{Decoder, RandomKey},
 };
@@ -9,14 +8,14 @@ use ordered_float::NotNan;
 use crate::{MaximumDiversity, MdpSolution};
 
 pub struct CurrentDecoder<'a> {
-    auxiliary: RefCell<Vec<(NotNan<RandomKey>, usize)>>,
+    auxiliary: Scratch<Vec<(NotNan<RandomKey>, usize)>>,
     problem: &'a MaximumDiversity,
 }
 
 impl<'a> CurrentDecoder<'a> {
     pub fn new(problem: &'a MaximumDiversity) -> Self {
         Self {
-            auxiliary: RefCell::new(vec![(NotNan::new(0.0).unwrap(), 0); problem.input_size]),
+            auxiliary: Scratch::new(vec![(NotNan::new(0.0).unwrap(), 0); problem.input_size]),
             problem,
         }
     }
@@ -26,7 +25,7 @@ impl Decoder for CurrentDecoder<'_> {
     type P = MaximumDiversity;
 
     fn decode(&self, member: &[RandomKey]) -> <Self::P as Problem>::Solution {
-        let mut aux = self.auxiliary.borrow_mut();
+        let mut aux = self.auxiliary.get();
 
         aux.iter_mut().enumerate().for_each(|(i, el)| {
             *el = (unsafe { NotNan::new_unchecked(member[i]) }, i);
@@ -1,7 +1,5 @@
-use std::cell::RefCell;
-
 use optimum::{
-    components::coverage::Coverage,
+    components::{coverage::Coverage, scratch::Scratch},
     core::Problem,
     metaheuristics::genetic::{Decoder, RandomKey},
 };
@@ -12,14 +10,14 @@ use crate::MdpSolution;
 
 pub struct ExperimentalDecoder<'a> {
     pub problem: &'a MaximumDiversity,
-    coverage: RefCell<Coverage<usize>>,
+    coverage: Scratch<Coverage<usize>>,
 }
 
 impl<'a> ExperimentalDecoder<'a> {
     pub fn new(problem: &'a MaximumDiversity) -> Self {
         Self {
             problem,
-            coverage: RefCell::new(Coverage::new(problem.input_size)),
+            coverage: Scratch::new(Coverage::new(problem.input_size)),
         }
     }
 }
@@ -32,7 +30,7 @@ impl Decoder for ExperimentalDecoder<'_> {
             elements: vec![0; self.problem.solution_size],
         };
 
-        let mut coverage = self.coverage.borrow_mut();
+        let mut coverage = self.coverage.get();
         coverage.reset();
 
         for (idx, key) in member.iter().copied().enumerate() {
@@ -1,6 +1,8 @@
-use std::cell::RefCell;
-
-use optimum::{components::coverage::Coverage, metaheuristics::genetic::Decoder};
+use optimum::{
+    components::{coverage::Coverage, scratch::Scratch},
+    core::Problem,
+    metaheuristics::genetic::{Decoder, RandomKey},
+};
 
 use crate::{
     problem::{Tsp, TspSolution},
@@ -9,14 +11,14 @@ use crate::{
 
 pub struct TspDecoder<'a> {
     problem: &'a Tsp,
-    coverage: RefCell<Coverage<usize>>,
+    coverage: Scratch<Coverage<usize>>,
 }
 
 impl<'a> TspDecoder<'a> {
     pub fn new(problem: &'a Tsp) -> Self {
         Self {
             problem,
-            coverage: RefCell::new(Coverage::new(CITIES)),
+            coverage: Scratch::new(Coverage::new(CITIES)),
         }
     }
 }
@@ -32,7 +34,7 @@ impl Decoder for TspDecoder<'_> {
             cities: vec![0; member.len()],
         };
 
-        let mut coverage = self.coverage.borrow_mut();
+        let mut coverage = self.coverage.get();
         coverage.reset();
 
         for (idx, key) in member.iter().copied().enumerate() {
@@ -73,7 +75,37 @@ impl Decoder for TspDecoder<'_> {
         solution
     }
 
+    /// Improves the decoded tour with a pass of city swaps and encodes it back into `member`.
+    fn decode_value_mut(&self, member: &mut [RandomKey]) -> <Self::P as Problem>::Value {
+        let mut solution = self.decode(member);
+
+        swap_pass(self.problem, &mut solution.cities);
+        encode(&solution, member);
+
+        self.problem.objective_function(solution).value()
+    }
+
     fn problem(&self) -> &Self::P {
         self.problem
     }
 }
+
+/// Applies every improving swap of two cities found in a single sweep over `cities`.
+fn swap_pass(problem: &Tsp, cities: &mut [usize]) {
+    for a in 0..cities.len() {
+        for b in (a + 1)..cities.len() {
+            if problem.swap_cost(cities, a, b, true) < problem.swap_cost(cities, a, b, false) {
+                cities.swap(a, b);
+            }
+        }
+    }
+}
+
+/// Writes keys which are decoded exactly into `solution`.
+fn encode(solution: &TspSolution, member: &mut [RandomKey]) {
+    let len = member.len() as f64;
+
+    for (key, city) in member.iter_mut().zip(solution.cities.iter().copied()) {
+        *key = (city as f64 + 0.5) / len;
+    }
+}
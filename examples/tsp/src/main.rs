@@ -2,8 +2,8 @@
 //!
 //! This example provides a definition of the TSP problem and a simple solver to the problem: apply the two opt move until a stop criterion is met.
 //!  
-//! Note that this implementation could be more efficient by not using `Clone` on the `TspSolution`.
-//! Initially, I implemented the code without it, but the code gets more complex and this is just an example.
+//! Two opt moves are compared through their [Delta][optimum::core::Delta], so neighbors aren't built
+//! until a move is applied.
 //!
 
 use std::{clone::Clone, time::Duration};
@@ -89,18 +89,17 @@ fn genetic(tsp: &Tsp, mut stop_criterion: impl StopCriterion<Tsp>) -> TspSolutio
     let mut brkga = Brkga::new(
         &tsp_decoder,
         thread_rng(),
-        Params {
-            population_size: 100.try_into().unwrap(),
-            member_size: CITIES.try_into().unwrap(),
-            elites: 20,
-            mutants: 30,
-            crossover_bias: 0.7,
-        },
+        Params::builder(100, CITIES)
+            .elites(20)
+            .mutants(30)
+            .crossover_bias(0.7)
+            .build()
+            .unwrap(),
         RandomMemberBuilder,
     );
-    println!("Initial solution's value: {:?}", brkga.best().value);
+    println!("Initial solution's value: {:?}", brkga.best().value());
     brkga.solve(&mut stop_criterion, &mut EmptyHook);
-    println!("Final solution's value: {:?}", brkga.best().value);
+    println!("Final solution's value: {:?}", brkga.best().value());
 
-    tsp_decoder.decode(&brkga.best().keys)
+    tsp_decoder.decode(brkga.best().keys())
 }
@@ -1,5 +1,5 @@
 use optimum::{
-    core::{Evaluation, Problem},
+    core::{Delta, Evaluation, Problem},
     metaheuristics::neighborhood::{Move, Neighborhood},
 };
 
@@ -19,9 +19,31 @@ pub struct TwoOptMove(pub usize, pub usize);
 
 impl Move<Tsp> for TwoOptMove {
     fn value(&self, problem: &Tsp, evaluation: &Evaluation<Tsp>) -> <Tsp as Problem>::Value {
-        let mut solution = evaluation.solution().clone();
-        solution.cities.swap(self.0, self.1);
-        problem.objective_function(solution).value()
+        let delta = self.delta(problem, evaluation).unwrap();
+        delta.apply(evaluation.value())
+    }
+
+    /// Only the edges touching the swapped cities change.
+    fn delta(
+        &self,
+        problem: &Tsp,
+        evaluation: &Evaluation<Tsp>,
+    ) -> Option<Delta<<Tsp as Problem>::Value>> {
+        let cities = &evaluation.solution().cities;
+        let before = problem.swap_cost(cities, self.0, self.1, false);
+        let after = problem.swap_cost(cities, self.0, self.1, true);
+
+        Some(Delta::between(before, after))
+    }
+
+    fn delta_matches(
+        &self,
+        _problem: &Tsp,
+        delta: Delta<<Tsp as Problem>::Value>,
+        from: <Tsp as Problem>::Value,
+        to: <Tsp as Problem>::Value,
+    ) -> bool {
+        Delta::between(from, to) == delta
     }
 
     fn apply(&self, problem: &Tsp, evaluation: Evaluation<Tsp>) -> Evaluation<Tsp> {
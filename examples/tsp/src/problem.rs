@@ -30,6 +30,31 @@ impl Problem for Tsp {
     }
 }
 
+impl Tsp {
+    /// The cost of the edges touching positions `a` and `b`, which are the only ones changed by swapping them.
+    ///
+    /// When `swapped` is true, it's the cost after swapping them, without changing `cities`.
+    pub fn swap_cost(&self, cities: &[usize], a: usize, b: usize, swapped: bool) -> usize {
+        let city = |position: usize| match position {
+            _ if !swapped => cities[position],
+            _ if position == a => cities[b],
+            _ if position == b => cities[a],
+            _ => cities[position],
+        };
+
+        let mut edges = [a.wrapping_sub(1), a, b.wrapping_sub(1), b];
+        edges.sort_unstable();
+
+        edges
+            .iter()
+            .enumerate()
+            .filter(|&(i, &edge)| i == 0 || edge != edges[i - 1])
+            .filter(|&(_, &edge)| edge < cities.len() - 1)
+            .map(|(_, &edge)| self.distances[[city(edge), city(edge + 1)]])
+            .sum()
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct TspSolution {
     pub cities: Vec<usize>,
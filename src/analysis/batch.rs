@@ -5,13 +5,20 @@ mod statistics;
 pub use statistics::{Gap, Statistics};
 
 use std::{
-    fmt::Debug,
+    fmt::{Debug, Display},
+    io::{self, Write},
     time::{Duration, Instant},
 };
 
 use typed_builder::TypedBuilder;
 
-use crate::core::{solver, Evaluation, Problem, Solver, StopCriterion};
+use crate::core::{
+    solver::{
+        self,
+        hook::{Record, Step},
+    },
+    Evaluation, Objective, Problem, Solver, StopCriterion,
+};
 
 /// A batch is a sequence of multiple executions of a stochastic solver which is often used to
 /// compare the solver's performance across different seed numbers.
@@ -152,6 +159,53 @@ impl<P: Problem, H> BatchResult<P, H> {
     }
 }
 
+/// Exports of the trajectories recorded by each execution, for e.g. convergence plots or
+/// time-to-target analyses.
+impl<P: Problem> BatchResult<P, Record<P::Value>> {
+    /// Writes the steps of every execution as CSV, with a header. The first column is the execution's number.
+    pub fn write_csv<W: Write>(&self, mut writer: W) -> io::Result<()>
+    where
+        P::Value: Display,
+    {
+        writeln!(writer, "execution,{}", Step::<P::Value>::CSV_HEADER)?;
+        for execution in &self.executions {
+            for step in execution.hook.steps() {
+                writeln!(writer, "{},{}", execution.number, step.csv())?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes the steps of every execution as JSON lines, including the execution's number in each object.
+    ///
+    /// As in [Record::write_json_lines], values are written through [Display].
+    pub fn write_json_lines<W: Write>(&self, mut writer: W) -> io::Result<()>
+    where
+        P::Value: Display,
+    {
+        for execution in &self.executions {
+            for step in execution.hook.steps() {
+                writeln!(
+                    writer,
+                    r#"{{"execution":{},{}}}"#,
+                    execution.number,
+                    step.json_fields()
+                )?;
+            }
+        }
+        Ok(())
+    }
+
+    /// How long each execution took to reach `target` in the `objective` direction, which is usually
+    /// [Problem::OBJECTIVE] or the [objective][Problem::objective] of the instance solved.
+    pub fn times_to_target(&self, target: P::Value, objective: Objective) -> Vec<Option<Duration>> {
+        self.executions
+            .iter()
+            .map(|execution| execution.hook.time_to_target(target, objective))
+            .collect()
+    }
+}
+
 impl<P, H> Debug for BatchResult<P, H>
 where
     P: Problem,
@@ -164,3 +218,47 @@ where
             .finish()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::core::{solver::test_helpers::Replay, stop_criterion::IterCriterion, Objective};
+
+    use super::*;
+
+    #[test]
+    fn trajectories_are_exported() {
+        let batch = Batch::builder()
+            .base_seed(0)
+            .executions(2)
+            .solver(|_, exec_number| Replay(vec![10, 10 - exec_number].into_iter()))
+            .stop_criterion(IterCriterion::new(10))
+            .hook(Record::new())
+            .build()
+            .run()
+            .unwrap();
+
+        let mut csv = Vec::new();
+        batch.write_csv(&mut csv).unwrap();
+        let csv = String::from_utf8(csv).unwrap();
+        let executions = csv
+            .lines()
+            .skip(1)
+            .map(|line| line.split(',').next().unwrap())
+            .collect::<Vec<_>>();
+
+        assert!(csv.starts_with("execution,iteration,elapsed,value,best\n"));
+        assert_eq!(executions, ["1", "1", "2", "2"]);
+
+        let mut json = Vec::new();
+        batch.write_json_lines(&mut json).unwrap();
+        let json = String::from_utf8(json).unwrap();
+        let last: serde_json::Value = serde_json::from_str(json.lines().last().unwrap()).unwrap();
+
+        assert_eq!(last["execution"], 2);
+        assert_eq!(last["best"], 8);
+
+        let times = batch.times_to_target(9, Objective::Min);
+        assert!(times[0].is_some() && times[1].is_some());
+        assert!(batch.times_to_target(8, Objective::Min)[0].is_none());
+    }
+}
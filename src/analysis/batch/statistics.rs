@@ -12,6 +12,7 @@ pub struct Statistics<'a, P: Problem, H> {
     value_sum: f64,
     time_sum: Duration,
     batch: &'a BatchResult<P, H>,
+    objective: Objective,
 }
 
 impl<'a, P: Problem, H> Statistics<'a, P, H>
@@ -31,9 +32,16 @@ where
             value_sum: score_sum,
             time_sum,
             batch,
+            objective: P::OBJECTIVE,
         }
     }
 
+    /// Takes the [best][Self::best] execution in the `objective` direction instead of [Problem::OBJECTIVE].
+    pub fn with_objective(mut self, objective: Objective) -> Self {
+        self.objective = objective;
+        self
+    }
+
     /// The average value of all executions
     pub fn average_value(&self) -> f64 {
         self.value_sum / self.batch.executions.len() as f64
@@ -63,7 +71,7 @@ where
     pub fn best(&self) -> &Execution<P, H> {
         let iter = self.batch.executions().iter();
 
-        match P::OBJECTIVE {
+        match self.objective {
             Objective::Min => iter.min_by_key(|exec| exec.evaluation.value()),
             Objective::Max => iter.max_by_key(|exec| exec.evaluation.value()),
         }
@@ -1,3 +1,5 @@
 pub mod coverage;
 pub mod elite_set;
+pub mod pareto_archive;
+pub mod scratch;
 pub mod selection_control;
@@ -0,0 +1,217 @@
+use core::fmt::Debug;
+use std::{slice, vec};
+
+use crate::core::{crowding_distances, Dominance, Evaluation, MultiObjective};
+
+/// Keeps the non-dominated [Evaluation]s found so far, i.e. an approximation of the Pareto front.
+///
+/// When a limit is given and the archive is full, the member in the most crowded region is discarded.
+/// See [crowding_distances].
+pub struct ParetoArchive<P: MultiObjective> {
+    elements: Vec<Evaluation<P>>,
+    limit: Option<usize>,
+}
+
+impl<P: MultiObjective> Debug for ParetoArchive<P>
+where
+    P::Solution: Debug,
+    P::Value: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ParetoArchive")
+            .field("elements", &self.elements)
+            .field("limit", &self.limit)
+            .finish()
+    }
+}
+
+impl<P: MultiObjective> Default for ParetoArchive<P> {
+    fn default() -> Self {
+        Self::unbounded()
+    }
+}
+
+impl<P: MultiObjective> ParetoArchive<P> {
+    /// Creates an archive which keeps at most `limit` members.
+    pub fn new(limit: usize) -> Self {
+        assert!(limit > 0, "Pareto archive limit must be greater than zero");
+
+        Self {
+            elements: Vec::with_capacity(limit),
+            limit: Some(limit),
+        }
+    }
+
+    /// Creates an archive which keeps every non-dominated member.
+    pub fn unbounded() -> Self {
+        Self {
+            elements: Vec::new(),
+            limit: None,
+        }
+    }
+
+    /// Inserts `candidate` if no member dominates or equals it, removing the members it dominates.
+    ///
+    /// It's given back when it isn't kept, which includes being discarded right away because the archive
+    /// is full and it's in the most crowded region.
+    pub fn try_insert(&mut self, candidate: Evaluation<P>) -> Result<(), Evaluation<P>> {
+        let rejected = self.elements.iter().any(|existing| {
+            matches!(
+                candidate.dominance(existing),
+                Dominance::Worse | Dominance::Equal
+            )
+        });
+        if rejected {
+            return Err(candidate);
+        }
+
+        self.elements
+            .retain(|existing| candidate.dominance(existing) != Dominance::Better);
+        self.elements.push(candidate);
+
+        if self.limit.is_some_and(|limit| self.elements.len() > limit) {
+            let last = self.elements.len() - 1;
+            let (position, removed) = self.remove_most_crowded();
+            if position == last {
+                return Err(removed);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Removes the member with the smallest crowding distance, returning it along with its position.
+    fn remove_most_crowded(&mut self) -> (usize, Evaluation<P>) {
+        let values = self.elements.iter().map(|e| e.value()).collect::<Vec<_>>();
+        let distances = crowding_distances::<P>(&values);
+
+        let position = distances
+            .iter()
+            .enumerate()
+            .min_by(|a, b| a.1.total_cmp(b.1))
+            .map(|(position, _)| position)
+            .unwrap();
+
+        (position, self.elements.swap_remove(position))
+    }
+
+    /// Number of members in the archive.
+    pub fn len(&self) -> usize {
+        self.elements.len()
+    }
+
+    /// True when nothing was inserted yet.
+    pub fn is_empty(&self) -> bool {
+        self.elements.is_empty()
+    }
+
+    /// Iterates over the members of the archive, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = &Evaluation<P>> {
+        self.into_iter()
+    }
+}
+
+impl<'a, P: MultiObjective> IntoIterator for &'a ParetoArchive<P> {
+    type Item = &'a Evaluation<P>;
+
+    type IntoIter = slice::Iter<'a, Evaluation<P>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.elements.iter()
+    }
+}
+
+impl<P: MultiObjective> IntoIterator for ParetoArchive<P> {
+    type Item = Evaluation<P>;
+
+    type IntoIter = vec::IntoIter<Evaluation<P>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.elements.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::{Objective, Problem};
+
+    use super::*;
+
+    struct Test;
+
+    impl Problem for Test {
+        type Solution = [usize; 2];
+
+        const OBJECTIVE: Objective = Objective::Min;
+
+        type Value = [usize; 2];
+
+        fn objective_function(&self, solution: Self::Solution) -> Evaluation<Self> {
+            Evaluation::new(solution, solution)
+        }
+    }
+
+    impl MultiObjective for Test {
+        type Component = usize;
+
+        const OBJECTIVES: &'static [Objective] = &[Objective::Min, Objective::Min];
+
+        fn component(value: &Self::Value, objective: usize) -> Self::Component {
+            value[objective]
+        }
+    }
+
+    fn insert_helper(archive: &mut ParetoArchive<Test>, value: [usize; 2]) -> bool {
+        archive.try_insert(Evaluation::new(value, value)).is_ok()
+    }
+
+    fn values(archive: &ParetoArchive<Test>) -> Vec<[usize; 2]> {
+        let mut values = archive.iter().map(|e| e.value()).collect::<Vec<_>>();
+        values.sort_unstable();
+        values
+    }
+
+    #[test]
+    fn only_non_dominated_members_are_kept() {
+        let mut archive = ParetoArchive::unbounded();
+
+        assert!(insert_helper(&mut archive, [5, 5]));
+        assert!(insert_helper(&mut archive, [2, 8]));
+        assert!(!insert_helper(&mut archive, [6, 6]));
+        assert!(!insert_helper(&mut archive, [5, 5]));
+        assert!(insert_helper(&mut archive, [4, 4]));
+
+        assert_eq!(values(&archive), [[2, 8], [4, 4]]);
+    }
+
+    #[test]
+    fn crowded_members_are_discarded() {
+        let mut archive = ParetoArchive::new(3);
+
+        for value in [[0, 10], [10, 0], [5, 5], [6, 4]] {
+            let kept = insert_helper(&mut archive, value);
+            assert_eq!(kept, values(&archive).contains(&value));
+        }
+
+        assert_eq!(archive.len(), 3);
+        assert_eq!(values(&archive)[0], [0, 10]);
+        assert_eq!(values(&archive)[2], [10, 0]);
+    }
+
+    #[test]
+    fn candidates_in_the_most_crowded_region_are_given_back() {
+        let mut archive = ParetoArchive::new(3);
+
+        for value in [[0, 10], [10, 0], [4, 4]] {
+            assert!(insert_helper(&mut archive, value));
+        }
+
+        // Next to [4, 4], while the boundaries are never discarded.
+        let candidate = Evaluation::new([5, 3], [5, 3]);
+        assert_eq!(
+            archive.try_insert(candidate).map_err(|it| it.value()),
+            Err([5, 3])
+        );
+        assert_eq!(values(&archive), [[0, 10], [4, 4], [10, 0]]);
+    }
+}
@@ -0,0 +1,106 @@
+use std::{
+    ops::{Deref, DerefMut},
+    sync::Mutex,
+};
+
+/// Reusable scratch space which can be shared between threads.
+///
+/// It's a replacement for the `RefCell<T>` often used to keep auxiliary structures
+/// inside a [Decoder][crate::metaheuristics::genetic::Decoder]. Each call to [get][Scratch::get]
+/// lends an instance exclusively, which goes back to the pool when the guard is dropped.
+/// A new instance is cloned from the prototype when every other one is in use, so there
+/// will be at most one instance per thread using it at the same time.
+#[derive(Debug)]
+pub struct Scratch<T> {
+    prototype: T,
+    pool: Mutex<Vec<T>>,
+}
+
+impl<T: Clone> Scratch<T> {
+    /// Creates a new scratch space whose instances are clones of `prototype`.
+    pub fn new(prototype: T) -> Self {
+        Self {
+            pool: Mutex::new(vec![prototype.clone()]),
+            prototype,
+        }
+    }
+
+    /// Borrows an instance of the scratch space until the returned guard is dropped.
+    ///
+    /// The instance is given as it was left by the previous borrower.
+    pub fn get(&self) -> ScratchGuard<'_, T> {
+        let value = self
+            .pool
+            .lock()
+            .expect("scratch pool poisoned")
+            .pop()
+            .unwrap_or_else(|| self.prototype.clone());
+
+        ScratchGuard {
+            scratch: self,
+            value: Some(value),
+        }
+    }
+}
+
+impl<T: Clone> Clone for Scratch<T> {
+    fn clone(&self) -> Self {
+        Self::new(self.prototype.clone())
+    }
+}
+
+/// An instance borrowed from a [Scratch].
+#[derive(Debug)]
+pub struct ScratchGuard<'a, T> {
+    scratch: &'a Scratch<T>,
+    value: Option<T>,
+}
+
+impl<T> Deref for ScratchGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        // `value` is only taken on drop.
+        self.value.as_ref().unwrap()
+    }
+}
+
+impl<T> DerefMut for ScratchGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.value.as_mut().unwrap()
+    }
+}
+
+impl<T> Drop for ScratchGuard<'_, T> {
+    fn drop(&mut self) {
+        if let (Some(value), Ok(mut pool)) = (self.value.take(), self.scratch.pool.lock()) {
+            pool.push(value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Scratch;
+
+    #[test]
+    fn instances_are_reused() {
+        let scratch = Scratch::new(vec![0; 3]);
+
+        scratch.get()[0] = 1;
+
+        assert_eq!(*scratch.get(), [1, 0, 0]);
+    }
+
+    #[test]
+    fn simultaneous_borrows_get_distinct_instances() {
+        let scratch = Scratch::new(0);
+
+        let mut a = scratch.get();
+        let mut b = scratch.get();
+        *a += 1;
+        *b += 2;
+
+        assert_eq!((*a, *b), (1, 2));
+    }
+}
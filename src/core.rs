@@ -8,7 +8,10 @@ pub mod solver;
 pub mod stop_criterion;
 
 #[doc(inline)]
-pub use problem::{compare_values, Comparison, Evaluation, Objective, Problem};
+pub use problem::{
+    compare_values, crowding_distances, dominance, Comparison, Constrained, Delta, Dominance,
+    Evaluation, FeasibilityFirst, FeasibilityValue, MultiObjective, Objective, Penalized, Problem,
+};
 
 pub use solver::Solver;
 pub use stop_criterion::StopCriterion;
@@ -1,8 +1,17 @@
+use std::cmp::Ordering;
+
+mod constrained;
+mod delta;
 mod evaluation;
+mod multi_objective;
 
+pub use constrained::{Constrained, FeasibilityFirst, FeasibilityValue, Penalized};
+pub use delta::Delta;
 pub use evaluation::{compare_values, Comparison, Evaluation};
+pub use multi_objective::{crowding_distances, dominance, Dominance, MultiObjective};
 
 /// Determines if you want to minimize or maximize the [objective_function][Problem::objective_function]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Objective {
     /// Minimization
     Min,
@@ -10,6 +19,27 @@ pub enum Objective {
     Max,
 }
 
+impl Objective {
+    /// The opposite direction.
+    pub const fn reversed(self) -> Self {
+        match self {
+            Objective::Min => Objective::Max,
+            Objective::Max => Objective::Min,
+        }
+    }
+
+    /// Tells if `a` is better, equal or worse than `b` in this direction.
+    pub fn compare<V: Ord>(self, a: V, b: V) -> Comparison {
+        match (self, a.cmp(&b)) {
+            (_, Ordering::Equal) => Comparison::Equal,
+            (Objective::Min, Ordering::Less) | (Objective::Max, Ordering::Greater) => {
+                Comparison::Better
+            }
+            _ => Comparison::Worse,
+        }
+    }
+}
+
 /// This trait is a core definition for the library, as every optimization task is related to a [Problem].
 ///
 /// ```
@@ -49,6 +79,8 @@ pub enum Objective {
 ///
 pub trait Problem {
     /// Defines if the problem seeks Minimization or Maximization of the [objective_function][Self::objective_function].
+    ///
+    /// It's the default of [objective][Self::objective].
     const OBJECTIVE: Objective;
     /// Determines the structure of the solution to the [Problem].
     type Solution;
@@ -57,4 +89,15 @@ pub trait Problem {
 
     /// Associates a [Value][Self::Value] to each [Solution][Self::Solution] to the [Problem] through an [Evaluation].
     fn objective_function(&self, solution: Self::Solution) -> Evaluation<Self>;
+
+    /// The direction of this instance of the problem, which is [OBJECTIVE][Self::OBJECTIVE] by default.
+    ///
+    /// Override it to choose the direction at runtime, e.g. to search for the worst case of a problem
+    /// usually minimized. Solvers which take the problem into account, such as [Brkga][crate::metaheuristics::genetic::brkga::Brkga]
+    /// and the [neighborhood][crate::metaheuristics::neighborhood] explorers, follow it.
+    /// Components without access to the problem, such as [compare_values], use [OBJECTIVE][Self::OBJECTIVE]
+    /// and usually have a way to receive another [Objective].
+    fn objective(&self) -> Objective {
+        Self::OBJECTIVE
+    }
 }
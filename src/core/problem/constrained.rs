@@ -0,0 +1,328 @@
+use std::{
+    cmp::Ordering,
+    fmt::Debug,
+    ops::{Add, Mul, Sub},
+};
+
+use super::{Evaluation, Objective, Problem};
+
+/// A [Problem] whose solutions may violate constraints.
+///
+/// The [Violation][Self::Violation] measures how far a solution is from being feasible, e.g. the weight above
+/// the capacity of a knapsack. It's always minimized, and its [Default] value means the solution is feasible.
+///
+/// To rank infeasible solutions, wrap the problem into [FeasibilityFirst] or [Penalized], whose
+/// [Value][Problem::Value]s combine the objective with the violation.
+pub trait Constrained: Problem {
+    /// How much a solution violates the constraints. The [Default] value means no violation.
+    type Violation: Ord + Copy + Default;
+
+    /// Measures the violation of the constraints by `solution`.
+    fn violation(&self, solution: &Self::Solution) -> Self::Violation;
+
+    /// True when `solution` doesn't violate any constraint.
+    fn is_feasible(&self, solution: &Self::Solution) -> bool {
+        self.violation(solution) == Self::Violation::default()
+    }
+}
+
+/// Ranks solutions by their violation first, so feasible solutions are always better than infeasible ones.
+/// Solutions with the same violation are ranked by the objective of the wrapped problem.
+///
+/// ```
+/// # use optimum::core::{Constrained, Evaluation, FeasibilityFirst, Objective, Problem};
+/// struct Budget(usize);
+///
+/// impl Problem for Budget {
+///     const OBJECTIVE: Objective = Objective::Max;
+///     type Solution = usize;
+///     type Value = usize;
+///
+///     fn objective_function(&self, spent: usize) -> Evaluation<Self> {
+///         Evaluation::new(spent, spent)
+///     }
+/// }
+///
+/// impl Constrained for Budget {
+///     type Violation = usize;
+///
+///     fn violation(&self, spent: &usize) -> usize {
+///         spent.saturating_sub(self.0)
+///     }
+/// }
+///
+/// let problem = FeasibilityFirst(Budget(10));
+/// let feasible = problem.objective_function(8);
+/// let infeasible = problem.objective_function(12);
+///
+/// assert!(feasible.value() > infeasible.value());
+/// assert!(feasible.value().is_feasible());
+/// ```
+#[derive(Debug, Clone)]
+pub struct FeasibilityFirst<P>(pub P);
+
+impl<P: Constrained> Problem for FeasibilityFirst<P> {
+    const OBJECTIVE: Objective = P::OBJECTIVE;
+
+    type Solution = P::Solution;
+
+    type Value = FeasibilityValue<P>;
+
+    fn objective_function(&self, solution: Self::Solution) -> Evaluation<Self> {
+        let violation = self.0.violation(&solution);
+        let evaluation = self.0.objective_function(solution);
+        let value = FeasibilityValue {
+            violation,
+            objective: evaluation.value(),
+            direction: self.0.objective(),
+        };
+
+        Evaluation::new(evaluation.into_solution(), value)
+    }
+
+    fn objective(&self) -> Objective {
+        self.0.objective()
+    }
+}
+
+impl<P: Constrained> Constrained for FeasibilityFirst<P> {
+    type Violation = P::Violation;
+
+    fn violation(&self, solution: &Self::Solution) -> Self::Violation {
+        self.0.violation(solution)
+    }
+}
+
+/// The [Value][Problem::Value] of [FeasibilityFirst].
+///
+/// Its order follows the `direction` of the wrapped problem: a smaller violation is always better and,
+/// for the same violation, the better objective wins.
+pub struct FeasibilityValue<P: Constrained> {
+    /// How much the solution violates the constraints.
+    pub violation: P::Violation,
+    /// The value given by the wrapped problem's objective function.
+    pub objective: P::Value,
+    /// The [objective][Problem::objective] of the wrapped problem, which tells how violations are ordered.
+    pub direction: Objective,
+}
+
+impl<P: Constrained> FeasibilityValue<P> {
+    /// True when the solution doesn't violate any constraint.
+    pub fn is_feasible(&self) -> bool {
+        self.violation == P::Violation::default()
+    }
+}
+
+impl<P: Constrained> Clone for FeasibilityValue<P> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<P: Constrained> Copy for FeasibilityValue<P> {}
+
+impl<P: Constrained> Debug for FeasibilityValue<P>
+where
+    P::Violation: Debug,
+    P::Value: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FeasibilityValue")
+            .field("violation", &self.violation)
+            .field("objective", &self.objective)
+            .field("direction", &self.direction)
+            .finish()
+    }
+}
+
+impl<P: Constrained> PartialEq for FeasibilityValue<P> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<P: Constrained> Eq for FeasibilityValue<P> {}
+
+impl<P: Constrained> PartialOrd for FeasibilityValue<P> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<P: Constrained> Ord for FeasibilityValue<P> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let by_violation = match self.direction {
+            // Smaller values are better, like smaller violations.
+            Objective::Min => self.violation.cmp(&other.violation),
+            Objective::Max => other.violation.cmp(&self.violation),
+        };
+
+        by_violation.then_with(|| self.objective.cmp(&other.objective))
+    }
+}
+
+/// Adds the violation times `weight` to the objective as a penalty, so infeasible solutions may still be
+/// better than feasible ones when their objective compensates the penalty.
+///
+/// For maximization, the penalty is subtracted, so the [Value][Problem::Value] should be signed.
+#[derive(Debug, Clone)]
+pub struct Penalized<P: Constrained> {
+    /// The problem being penalized.
+    pub problem: P,
+    /// How much each unit of violation costs.
+    pub weight: P::Value,
+}
+
+impl<P> Problem for Penalized<P>
+where
+    P: Constrained,
+    P::Value: From<P::Violation>
+        + Add<Output = P::Value>
+        + Sub<Output = P::Value>
+        + Mul<Output = P::Value>,
+{
+    const OBJECTIVE: Objective = P::OBJECTIVE;
+
+    type Solution = P::Solution;
+
+    type Value = P::Value;
+
+    fn objective_function(&self, solution: Self::Solution) -> Evaluation<Self> {
+        let penalty = self.weight * P::Value::from(self.problem.violation(&solution));
+        let evaluation = self.problem.objective_function(solution);
+
+        let value = match self.problem.objective() {
+            Objective::Min => evaluation.value() + penalty,
+            Objective::Max => evaluation.value() - penalty,
+        };
+
+        Evaluation::new(evaluation.into_solution(), value)
+    }
+
+    fn objective(&self) -> Objective {
+        self.problem.objective()
+    }
+}
+
+impl<P> Constrained for Penalized<P>
+where
+    Penalized<P>: Problem<Solution = P::Solution>,
+    P: Constrained,
+{
+    type Violation = P::Violation;
+
+    fn violation(&self, solution: &Self::Solution) -> Self::Violation {
+        self.problem.violation(solution)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        components::elite_set::EliteSet,
+        core::{compare_values, Comparison},
+    };
+
+    use super::*;
+
+    /// Choose a number, as large as possible but not above the limit.
+    struct Limit(i64);
+
+    impl Problem for Limit {
+        const OBJECTIVE: Objective = Objective::Max;
+
+        type Solution = i64;
+
+        type Value = i64;
+
+        fn objective_function(&self, solution: Self::Solution) -> Evaluation<Self> {
+            Evaluation::new(solution, solution)
+        }
+    }
+
+    impl Constrained for Limit {
+        type Violation = i64;
+
+        fn violation(&self, solution: &Self::Solution) -> Self::Violation {
+            (solution - self.0).max(0)
+        }
+    }
+
+    /// Choose a number, as small as possible but not below the limit, which is only minimized at runtime.
+    struct Floor(i64);
+
+    impl Problem for Floor {
+        const OBJECTIVE: Objective = Objective::Max;
+
+        type Solution = i64;
+
+        type Value = i64;
+
+        fn objective_function(&self, solution: Self::Solution) -> Evaluation<Self> {
+            Evaluation::new(solution, solution)
+        }
+
+        fn objective(&self) -> Objective {
+            Objective::Min
+        }
+    }
+
+    impl Constrained for Floor {
+        type Violation = i64;
+
+        fn violation(&self, solution: &Self::Solution) -> Self::Violation {
+            (self.0 - solution).max(0)
+        }
+    }
+
+    #[test]
+    fn feasible_solutions_come_first() {
+        let problem = FeasibilityFirst(Limit(10));
+        let value = |solution| problem.objective_function(solution).value();
+
+        type P = FeasibilityFirst<Limit>;
+        assert_eq!(compare_values::<P>(value(5), value(11)), Comparison::Better);
+        assert_eq!(compare_values::<P>(value(12), value(11)), Comparison::Worse);
+        assert_eq!(compare_values::<P>(value(9), value(5)), Comparison::Better);
+        assert!(problem.is_feasible(&10));
+
+        let mut elite = EliteSet::<P>::new(2, value(1000));
+        for solution in [20, 3, 15, 7] {
+            elite.try_insert(problem.objective_function(solution)).ok();
+        }
+        let mut kept = elite.iter().map(|e| *e.solution()).collect::<Vec<_>>();
+        kept.sort_unstable();
+        assert_eq!(kept, [3, 7]);
+    }
+
+    #[test]
+    fn penalties_are_weighted() {
+        let problem = Penalized {
+            problem: Limit(10),
+            weight: 3,
+        };
+
+        assert_eq!(problem.objective_function(8).value(), 8);
+        assert_eq!(problem.objective_function(12).value(), 6);
+        assert!(!problem.is_feasible(&12));
+    }
+
+    #[test]
+    fn runtime_objectives_are_followed() {
+        let problem = FeasibilityFirst(Floor(3));
+        let value = |solution| problem.objective_function(solution).value();
+        let objective = problem.objective();
+
+        assert_eq!(objective, Objective::Min);
+        assert_eq!(objective.compare(value(4), value(5)), Comparison::Better);
+        assert_eq!(objective.compare(value(3), value(2)), Comparison::Better);
+
+        let problem = Penalized {
+            problem: Floor(10),
+            weight: 3,
+        };
+
+        assert_eq!(problem.objective(), Objective::Min);
+        assert_eq!(problem.objective_function(8).value(), 14);
+    }
+}
@@ -0,0 +1,100 @@
+use std::{
+    cmp::Ordering,
+    ops::{Add, Sub},
+};
+
+use super::{Comparison, Objective};
+
+/// The difference between the [Value][crate::core::Problem::Value]s of two solutions, e.g. before and after
+/// applying a [Move][crate::metaheuristics::neighborhood::Move].
+///
+/// It keeps the magnitude and the direction apart, so unsigned values may be used.
+/// Its order is the one of the signed difference.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Delta<V> {
+    /// The value increases by the given amount.
+    Increase(V),
+    /// The value decreases by the given amount.
+    Decrease(V),
+    /// The value doesn't change.
+    Zero,
+}
+
+impl<V: Ord + Copy> Delta<V> {
+    /// The difference from `from` to `to`.
+    pub fn between(from: V, to: V) -> Self
+    where
+        V: Sub<Output = V>,
+    {
+        match to.cmp(&from) {
+            Ordering::Greater => Delta::Increase(to - from),
+            Ordering::Less => Delta::Decrease(from - to),
+            Ordering::Equal => Delta::Zero,
+        }
+    }
+
+    /// The value reached by changing `value` by `self`.
+    pub fn apply(self, value: V) -> V
+    where
+        V: Add<Output = V> + Sub<Output = V>,
+    {
+        match self {
+            Delta::Increase(amount) => value + amount,
+            Delta::Decrease(amount) => value - amount,
+            Delta::Zero => value,
+        }
+    }
+
+    /// Tells if the changed value is better, equal or worse than the original one in the `objective` direction.
+    pub fn comparison(self, objective: Objective) -> Comparison {
+        self.compare(Delta::Zero, objective)
+    }
+
+    /// Tells if changing a value by `self` is better, equal or worse than changing it by `other`.
+    pub fn compare(self, other: Self, objective: Objective) -> Comparison {
+        objective.compare(self, other)
+    }
+}
+
+impl<V: Ord> PartialOrd for Delta<V> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<V: Ord> Ord for Delta<V> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        use Delta::*;
+
+        match (self, other) {
+            (Increase(a), Increase(b)) => a.cmp(b),
+            (Decrease(a), Decrease(b)) => b.cmp(a),
+            (Zero, Zero) => Ordering::Equal,
+            (Increase(_), _) | (Zero, Decrease(_)) => Ordering::Greater,
+            (Decrease(_), _) | (Zero, Increase(_)) => Ordering::Less,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deltas_follow_the_objective() {
+        let decrease = Delta::between(7, 5);
+        let increase = Delta::between(5, 7);
+
+        assert_eq!(decrease, Delta::Decrease(2));
+        assert_eq!(decrease.apply(7), 5);
+        assert_eq!(Delta::between(3, 3), Delta::Zero);
+
+        assert!(Delta::Decrease(3) < decrease && decrease < Delta::Zero);
+        assert_eq!(decrease.comparison(Objective::Min), Comparison::Better);
+        assert_eq!(increase.comparison(Objective::Max), Comparison::Better);
+        assert_eq!(
+            Delta::Increase(1).compare(increase, Objective::Max),
+            Comparison::Worse
+        );
+    }
+}
@@ -1,6 +1,6 @@
-use std::{cmp::Ordering, fmt::Debug};
+use std::fmt::Debug;
 
-use super::{Objective, Problem};
+use super::Problem;
 
 /// Represents the association of a [Solution][Problem::Solution] with a [Value][Problem::Value] through the [objective function][Problem::objective_function].
 pub struct Evaluation<P: Problem + ?Sized> {
@@ -74,20 +74,11 @@ impl<P: Problem> Evaluation<P> {
     }
 }
 
-/// Compare `a` with `b` value directly.
+/// Compare `a` with `b` value directly, following [Problem::OBJECTIVE].
+///
+/// To follow the [objective][Problem::objective] of a problem instance, use [Objective::compare][super::Objective::compare].
 pub fn compare_values<P: Problem>(a: P::Value, b: P::Value) -> Comparison {
-    match P::OBJECTIVE {
-        Objective::Min => match a.cmp(&b) {
-            Ordering::Less => Comparison::Better,
-            Ordering::Equal => Comparison::Equal,
-            Ordering::Greater => Comparison::Worse,
-        },
-        Objective::Max => match a.cmp(&b) {
-            Ordering::Less => Comparison::Worse,
-            Ordering::Equal => Comparison::Equal,
-            Ordering::Greater => Comparison::Better,
-        },
-    }
+    P::OBJECTIVE.compare(a, b)
 }
 
 /// Defines a quality based comparison between two [Evaluation]s.
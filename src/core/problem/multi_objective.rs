@@ -0,0 +1,178 @@
+use std::cmp::Ordering;
+
+use num_traits::ToPrimitive;
+
+use super::{Evaluation, Objective, Problem};
+
+/// A [Problem] with many objectives, each one minimized or maximized independently.
+///
+/// Its [Value][Problem::Value] holds one [Component][Self::Component] per objective, e.g. `[usize; 2]`
+/// for makespan and cost. [Problem::OBJECTIVE] is still used by single-objective algorithms,
+/// which rank values by their [Ord], so multi-objective algorithms should be preferred.
+///
+/// ```
+/// # use optimum::core::{dominance, Dominance, Evaluation, MultiObjective, Objective, Problem};
+/// struct Schedule;
+///
+/// impl Problem for Schedule {
+///     const OBJECTIVE: Objective = Objective::Min;
+///     /// Number of machines rented.
+///     type Solution = usize;
+///     /// Makespan and cost.
+///     type Value = [usize; 2];
+///
+///     fn objective_function(&self, machines: usize) -> Evaluation<Self> {
+///         Evaluation::new(machines, [100 / machines, 10 * machines])
+///     }
+/// }
+///
+/// impl MultiObjective for Schedule {
+///     type Component = usize;
+///     const OBJECTIVES: &'static [Objective] = &[Objective::Min, Objective::Min];
+///
+///     fn component(value: &[usize; 2], objective: usize) -> usize {
+///         value[objective]
+///     }
+/// }
+///
+/// let value = |machines| Schedule.objective_function(machines).value();
+///
+/// assert_eq!(dominance::<Schedule>(value(2), value(4)), Dominance::Incomparable);
+/// // Both finish at time 2, but 40 machines cost more.
+/// assert_eq!(dominance::<Schedule>(value(40), value(34)), Dominance::Worse);
+/// ```
+pub trait MultiObjective: Problem {
+    /// The value of a single objective.
+    type Component: Ord + Copy + ToPrimitive;
+
+    /// Whether each objective is minimized or maximized. Its length is the number of objectives.
+    const OBJECTIVES: &'static [Objective];
+
+    /// The value of `value` for the objective at position `objective`.
+    fn component(value: &Self::Value, objective: usize) -> Self::Component;
+}
+
+/// The result of comparing two values by Pareto dominance.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Dominance {
+    /// The first dominates the second: it's no worse in every objective and better in at least one.
+    Better,
+    /// Both are equally valuable in every objective.
+    Equal,
+    /// The first is dominated by the second.
+    Worse,
+    /// Each one is better than the other in some objective.
+    Incomparable,
+}
+
+/// Compares `a` with `b` by Pareto dominance, following the direction of each objective.
+pub fn dominance<P: MultiObjective>(a: P::Value, b: P::Value) -> Dominance {
+    let (mut better, mut worse) = (false, false);
+
+    for (objective, direction) in P::OBJECTIVES.iter().enumerate() {
+        let ordering = P::component(&a, objective).cmp(&P::component(&b, objective));
+
+        match (direction, ordering) {
+            (_, Ordering::Equal) => {}
+            (Objective::Min, Ordering::Less) | (Objective::Max, Ordering::Greater) => better = true,
+            _ => worse = true,
+        }
+    }
+
+    match (better, worse) {
+        (true, false) => Dominance::Better,
+        (false, true) => Dominance::Worse,
+        (false, false) => Dominance::Equal,
+        (true, true) => Dominance::Incomparable,
+    }
+}
+
+impl<P: MultiObjective> Evaluation<P> {
+    /// Compares `self` with `other` by Pareto dominance.
+    pub fn dominance(&self, other: &Self) -> Dominance {
+        dominance::<P>(self.value(), other.value())
+    }
+}
+
+/// The crowding distance of each one of `values`, as defined by NSGA-II.
+///
+/// It sums, over all objectives, the normalized distance between the neighbors of a value.
+/// Values at the boundaries of any objective have infinite distance. Larger distances mean less crowded regions.
+pub fn crowding_distances<P: MultiObjective>(values: &[P::Value]) -> Vec<f64> {
+    let mut distances = vec![0.0; values.len()];
+    let mut order = (0..values.len()).collect::<Vec<_>>();
+
+    for objective in 0..P::OBJECTIVES.len() {
+        let component = |position: usize| {
+            P::component(&values[position], objective)
+                .to_f64()
+                .unwrap_or(f64::NAN)
+        };
+        order.sort_by_key(|&position| P::component(&values[position], objective));
+
+        let (Some(&first), Some(&last)) = (order.first(), order.last()) else {
+            break;
+        };
+        distances[first] = f64::INFINITY;
+        distances[last] = f64::INFINITY;
+
+        let range = component(last) - component(first);
+        if range <= 0.0 {
+            continue;
+        }
+
+        for window in order.windows(3) {
+            distances[window[1]] += (component(window[2]) - component(window[0])) / range;
+        }
+    }
+
+    distances
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Minimize the first component and maximize the second one.
+    struct Trade;
+
+    impl Problem for Trade {
+        const OBJECTIVE: Objective = Objective::Min;
+
+        type Solution = [u32; 2];
+
+        type Value = [u32; 2];
+
+        fn objective_function(&self, solution: Self::Solution) -> Evaluation<Self> {
+            Evaluation::new(solution, solution)
+        }
+    }
+
+    impl MultiObjective for Trade {
+        type Component = u32;
+
+        const OBJECTIVES: &'static [Objective] = &[Objective::Min, Objective::Max];
+
+        fn component(value: &Self::Value, objective: usize) -> Self::Component {
+            value[objective]
+        }
+    }
+
+    #[test]
+    fn dominance_follows_each_direction() {
+        assert_eq!(dominance::<Trade>([1, 5], [2, 5]), Dominance::Better);
+        assert_eq!(dominance::<Trade>([1, 4], [1, 5]), Dominance::Worse);
+        assert_eq!(dominance::<Trade>([1, 4], [2, 5]), Dominance::Incomparable);
+        assert_eq!(dominance::<Trade>([3, 3], [3, 3]), Dominance::Equal);
+    }
+
+    #[test]
+    fn boundaries_are_the_least_crowded() {
+        let distances = crowding_distances::<Trade>(&[[0, 0], [1, 1], [3, 3], [4, 4]]);
+
+        assert_eq!(distances[0], f64::INFINITY);
+        assert_eq!(distances[3], f64::INFINITY);
+        assert_eq!(distances[1], 1.5);
+        assert_eq!(distances[2], 1.5);
+    }
+}
@@ -1,8 +1,10 @@
 //! See [Solver].
 
-use crate::core::stop_criterion::StopCriterion;
+use std::time::{Duration, Instant};
 
-use super::{Comparison, Evaluation, Problem};
+use crate::core::stop_criterion::{Context, StopCriterion};
+
+use super::{Comparison, Evaluation, Objective, Problem};
 
 /// A solver is a procedure which seeks to find a good solution for a given [Problem].
 ///
@@ -18,36 +20,177 @@ pub trait Solver<SC: StopCriterion<Self::P>, H: IterHook<Self::P>> {
     /// A iteration consists on a step to generate a candidate solution
     fn iterate(&mut self, stop_criterion: &mut SC, hook: &mut H) -> Option<Evaluation<Self::P>>;
 
+    /// The direction in which candidates are compared. It's [Problem::OBJECTIVE] by default.
+    ///
+    /// Solvers which hold the problem should return its [objective][Problem::objective].
+    fn objective(&self) -> Objective {
+        <Self::P as Problem>::OBJECTIVE
+    }
+
+    /// Number of evaluations of the objective function performed so far, including the ones done before solving,
+    /// such as for building an initial population. Solvers which don't keep track of it return zero, so an
+    /// [EvaluationCriterion][crate::core::stop_criterion::EvaluationCriterion] can't be used with them.
+    ///
+    /// The evaluations are reported to the stop criterion through [StopCriterion::evaluated] after each iteration.
+    fn evaluations(&self) -> usize {
+        0
+    }
+
     /// Execute the whole process defined by the solver to achieve a good solution
     ///
     /// By default, it executes [iterate][Self::iterate] while the stop criterion isn't met and returns
     /// the best solution found among all iterations.
     fn solve(&mut self, stop_criterion: &mut SC, hook: &mut H) -> Option<Evaluation<Self::P>> {
-        let mut best_evaluation = self.iterate(stop_criterion, hook)?;
-        hook.iterated(&best_evaluation);
-        stop_criterion.update(best_evaluation.value());
-
-        while !stop_criterion.should_stop() {
-            let candidate = {
-                match self.iterate(stop_criterion, hook) {
-                    Some(s) => s,
-                    None => break,
-                }
-            };
-
-            stop_criterion.update(candidate.value());
-            hook.iterated(&candidate);
-
-            if let Comparison::Better = candidate.compare(&best_evaluation) {
-                hook.better_changed(&best_evaluation, &candidate);
-                best_evaluation = candidate
-            }
+        drive(self, stop_criterion, hook, |_, _| {}, |_| {})
+    }
+
+    /// Like [solve][Self::solve], but yields each new global best as it's found. See [Incumbents].
+    fn incumbents<'a>(
+        &'a mut self,
+        stop_criterion: &'a mut SC,
+        hook: &'a mut H,
+    ) -> Incumbents<'a, Self, SC, H>
+    where
+        Self: Sized,
+    {
+        Incumbents::new(self, stop_criterion, hook)
+    }
+}
+
+/// The loop of [Solver::solve], which also calls `before` ahead of each iteration and `improved` with each new best.
+pub(crate) fn drive<S, SC, H>(
+    solver: &mut S,
+    stop_criterion: &mut SC,
+    hook: &mut H,
+    mut before: impl FnMut(&mut S, &Tracker<S::P>),
+    mut improved: impl FnMut(&Evaluation<S::P>),
+) -> Option<Evaluation<S::P>>
+where
+    S: Solver<SC, H> + ?Sized,
+    SC: StopCriterion<S::P>,
+    H: IterHook<S::P>,
+{
+    let mut tracker = Tracker::new(solver.objective());
+    stop_criterion.started();
+    hook.started();
+
+    while tracker.best().is_none() || !stop_criterion.should_stop() {
+        before(solver, &tracker);
+
+        let candidate = match solver.iterate(stop_criterion, hook) {
+            Some(candidate) => candidate,
+            None => break,
+        };
+
+        if tracker.track(candidate, solver.evaluations(), stop_criterion, hook) {
+            improved(tracker.best().expect("an improvement is the best"));
+        }
+    }
+
+    tracker.into_best()
+}
+
+/// Keeps track of the best evaluation of a run, telling the hook and the stop criterion what happens
+/// in each iteration as [Solver::solve] does.
+pub(crate) struct Tracker<P: Problem> {
+    objective: Objective,
+    best: Option<Evaluation<P>>,
+    iteration: usize,
+    start: Instant,
+    /// Evaluations already reported to the stop criterion.
+    reported: usize,
+}
+
+impl<P: Problem> Tracker<P> {
+    pub(crate) fn new(objective: Objective) -> Self {
+        Self {
+            objective,
+            best: None,
+            iteration: 0,
+            start: Instant::now(),
+            reported: 0,
+        }
+    }
+
+    /// Takes the `candidate` generated by an iteration, given the `evaluations` performed so far by the solver.
+    ///
+    /// Returns whether it became the best evaluation.
+    pub(crate) fn track(
+        &mut self,
+        candidate: Evaluation<P>,
+        evaluations: usize,
+        stop_criterion: &mut impl StopCriterion<P>,
+        hook: &mut impl IterHook<P>,
+    ) -> bool {
+        self.iteration += 1;
+
+        let count = evaluations.saturating_sub(self.reported);
+        if count > 0 {
+            stop_criterion.evaluated(count);
         }
+        self.reported = evaluations;
+
+        hook.iterated(&candidate);
+
+        let improved = match &self.best {
+            Some(best) => self.is_better(&candidate, best),
+            None => true,
+        };
+
+        // The candidate is kept here when it isn't the new best.
+        let rejected = if improved {
+            if let Some(old) = &self.best {
+                hook.better_changed(old, &candidate);
+            }
+            self.best = Some(candidate);
+            None
+        } else {
+            Some(candidate)
+        };
+
+        let best = self
+            .best
+            .as_ref()
+            .expect("the first candidate is always the best");
+        stop_criterion.update(Context {
+            candidate: rejected.as_ref().unwrap_or(best),
+            best: best.value(),
+            improved,
+            elapsed: self.start.elapsed(),
+            iteration: self.iteration,
+        });
 
-        Some(best_evaluation)
+        improved
+    }
+
+    pub(crate) fn is_better(&self, a: &Evaluation<P>, b: &Evaluation<P>) -> bool {
+        self.objective.compare(a.value(), b.value()) == Comparison::Better
+    }
+
+    pub(crate) fn best(&self) -> Option<&Evaluation<P>> {
+        self.best.as_ref()
+    }
+
+    pub(crate) fn into_best(self) -> Option<Evaluation<P>> {
+        self.best
+    }
+
+    pub(crate) fn iteration(&self) -> usize {
+        self.iteration
+    }
+
+    pub(crate) fn elapsed(&self) -> Duration {
+        self.start.elapsed()
     }
 }
 
 pub mod hook;
+pub mod incumbents;
+pub mod portfolio;
+
+#[cfg(test)]
+pub(crate) mod test_helpers;
 
 pub use hook::IterHook;
+pub use incumbents::{Incumbent, Incumbents};
+pub use portfolio::{AcceptsIncumbent, Portfolio};
@@ -3,10 +3,17 @@
 //!  It's highly recommended that you create `Hook` traits for your metaheuristics to allow callers to add custom
 //! behavior into your metaheuristic.
 
+mod record;
+
+pub use record::{Record, Step};
+
 use super::super::{Evaluation, Problem};
 
 /// This trait allows callers to hook into special moments in the execution of the `Solver` to do things such as logging.
 pub trait IterHook<P: Problem> {
+    /// Called once, right before the first iteration.
+    fn started(&mut self) {}
+
     /// Called right after the iteration is performed. `new` is the newly generated evaluation yield by [iterate][super::Solver::iterate].
     fn iterated(&mut self, _new: &Evaluation<P>) {}
 
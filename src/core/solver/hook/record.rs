@@ -0,0 +1,205 @@
+use std::{
+    fmt::Display,
+    io::{self, Write},
+    time::{Duration, Instant},
+};
+
+use crate::core::{Comparison, Evaluation, Objective, Problem};
+
+use super::IterHook;
+
+/// What [Record] keeps about each iteration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Step<V> {
+    /// The iteration number, starting from one.
+    pub iteration: usize,
+    /// Time since the solver [started][IterHook::started].
+    pub elapsed: Duration,
+    /// The value generated in this iteration.
+    pub value: V,
+    /// The best value found up to this iteration, including it.
+    pub best: V,
+}
+
+impl<V: Display> Step<V> {
+    pub(crate) const CSV_HEADER: &'static str = "iteration,elapsed,value,best";
+
+    pub(crate) fn csv(&self) -> String {
+        format!(
+            "{},{},{},{}",
+            self.iteration,
+            self.elapsed.as_secs_f64(),
+            self.value,
+            self.best
+        )
+    }
+
+    /// The fields of a JSON object, without the braces.
+    ///
+    /// Values are written through [Display] as they are, see [Record::write_json_lines].
+    pub(crate) fn json_fields(&self) -> String {
+        format!(
+            r#""iteration":{},"elapsed":{},"value":{},"best":{}"#,
+            self.iteration,
+            self.elapsed.as_secs_f64(),
+            self.value,
+            self.best
+        )
+    }
+}
+
+/// Records the trajectory of a solver: the value of each iteration, the best value so far and when it happened.
+///
+/// Times are measured from the call to [started][IterHook::started], which [Solver::solve][crate::core::Solver::solve]
+/// does before the first iteration. The best value follows [better_changed][IterHook::better_changed], so it's
+/// the one chosen by the solver.
+///
+/// The trajectory may be written as CSV or JSON lines, to e.g. plot the convergence of the solver.
+/// Values are written through [Display], so they should be written as numbers by it.
+///
+/// ```
+/// # use optimum::core::solver::hook::Record;
+/// let record = Record::<usize>::new();
+/// // ... solve something with it
+/// let mut csv = Vec::new();
+/// record.write_csv(&mut csv).unwrap();
+///
+/// assert_eq!(String::from_utf8(csv).unwrap(), "iteration,elapsed,value,best\n");
+/// ```
+#[derive(Debug, Clone)]
+pub struct Record<V> {
+    steps: Vec<Step<V>>,
+    start: Option<Instant>,
+}
+
+impl<V> Default for Record<V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<V> Record<V> {
+    /// Creates an empty record.
+    pub fn new() -> Self {
+        Self {
+            steps: Vec::new(),
+            start: None,
+        }
+    }
+
+    /// Every iteration recorded, in order.
+    pub fn steps(&self) -> &[Step<V>] {
+        &self.steps
+    }
+}
+
+impl<V: Copy + Ord> Record<V> {
+    /// The best value recorded.
+    pub fn best(&self) -> Option<V> {
+        self.steps.last().map(|step| step.best)
+    }
+
+    /// How long it took to find a value at least as good as `target` in the `objective` direction.
+    pub fn time_to_target(&self, target: V, objective: Objective) -> Option<Duration> {
+        self.steps
+            .iter()
+            .find(|step| objective.compare(step.best, target) != Comparison::Worse)
+            .map(|step| step.elapsed)
+    }
+}
+
+impl<V: Display> Record<V> {
+    /// Writes the steps as CSV, with a header.
+    pub fn write_csv<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        writeln!(writer, "{}", Step::<V>::CSV_HEADER)?;
+        for step in &self.steps {
+            writeln!(writer, "{}", step.csv())?;
+        }
+        Ok(())
+    }
+
+    /// Writes the steps as JSON lines, i.e. one JSON object per line.
+    ///
+    /// Values are written through [Display] without quotes, so the lines are only valid JSON if it writes them
+    /// as JSON numbers, which primitive numbers do unless they're non finite floats.
+    pub fn write_json_lines<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        for step in &self.steps {
+            writeln!(writer, "{{{}}}", step.json_fields())?;
+        }
+        Ok(())
+    }
+}
+
+impl<P: Problem> IterHook<P> for Record<P::Value> {
+    fn started(&mut self) {
+        self.start.get_or_insert_with(Instant::now);
+    }
+
+    fn iterated(&mut self, new: &Evaluation<P>) {
+        let start = *self.start.get_or_insert_with(Instant::now);
+        let value = new.value();
+
+        self.steps.push(Step {
+            iteration: self.steps.len() + 1,
+            elapsed: start.elapsed(),
+            value,
+            best: self.steps.last().map_or(value, |step| step.best),
+        });
+    }
+
+    fn better_changed(&mut self, _: &Evaluation<P>, new: &Evaluation<P>) {
+        if let Some(step) = self.steps.last_mut() {
+            step.best = new.value();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::{solver::test_helpers::Replay, stop_criterion::IterCriterion, Solver};
+
+    use super::*;
+
+    #[test]
+    fn trajectory_is_recorded() {
+        let mut record = Record::new();
+        Replay(vec![5, 7, 3, 4].into_iter()).solve(&mut IterCriterion::new(10), &mut record);
+
+        let steps = record
+            .steps()
+            .iter()
+            .map(|step| (step.iteration, step.value, step.best))
+            .collect::<Vec<_>>();
+
+        assert_eq!(steps, [(1, 5, 5), (2, 7, 5), (3, 3, 3), (4, 4, 3)]);
+        assert_eq!(record.best(), Some(3));
+        assert!(record.time_to_target(4, Objective::Min).is_some());
+        assert!(record.time_to_target(2, Objective::Min).is_none());
+    }
+
+    #[test]
+    fn steps_are_exported() {
+        let mut record = Record::new();
+        Replay(vec![2, 1].into_iter()).solve(&mut IterCriterion::new(10), &mut record);
+
+        let mut csv = Vec::new();
+        record.write_csv(&mut csv).unwrap();
+        let csv = String::from_utf8(csv).unwrap();
+        let lines = csv.lines().collect::<Vec<_>>();
+
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0], "iteration,elapsed,value,best");
+        assert!(lines[2].starts_with("2,") && lines[2].ends_with(",1,1"));
+
+        let mut json = Vec::new();
+        record.write_json_lines(&mut json).unwrap();
+        let json = String::from_utf8(json).unwrap();
+
+        for (line, step) in json.lines().zip(record.steps()) {
+            let object: serde_json::Value = serde_json::from_str(line).unwrap();
+            assert_eq!(object["iteration"], step.iteration);
+            assert_eq!(object["value"], step.value);
+            assert_eq!(object["best"], step.best);
+        }
+    }
+}
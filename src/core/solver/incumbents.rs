@@ -0,0 +1,215 @@
+//! Defines [Incumbents], an [Iterator] over the improving solutions found by a [Solver].
+
+use std::{fmt::Debug, iter::FusedIterator, time::Duration};
+
+use super::{
+    super::{Evaluation, Problem, StopCriterion},
+    IterHook, Solver, Tracker,
+};
+
+/// A new global best found by a [Solver].
+pub struct Incumbent<P: Problem> {
+    /// The evaluation which became the best one.
+    pub evaluation: Evaluation<P>,
+    /// The iteration in which it was found, starting from one.
+    pub iteration: usize,
+    /// Time since the [Incumbents] were created.
+    pub elapsed: Duration,
+}
+
+impl<P: Problem> Clone for Incumbent<P>
+where
+    P::Solution: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            evaluation: self.evaluation.clone(),
+            iteration: self.iteration,
+            elapsed: self.elapsed,
+        }
+    }
+}
+
+impl<P: Problem> Debug for Incumbent<P>
+where
+    P::Solution: Debug,
+    P::Value: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Incumbent")
+            .field("evaluation", &self.evaluation)
+            .field("iteration", &self.iteration)
+            .field("elapsed", &self.elapsed)
+            .finish()
+    }
+}
+
+/// Runs a [Solver] lazily, yielding an [Incumbent] each time the global best changes.
+///
+/// It performs the same steps as [solve][Solver::solve], including the calls to the hook, but gives control back
+/// to the caller on every improvement. Therefore, the caller may display the progress, forward the incumbents
+/// elsewhere or stop early by just dropping the iterator.
+///
+/// The first evaluation generated is always yielded. The iterator ends when the stop criterion is met or
+/// when the solver has nothing else to generate. Create it with [Solver::incumbents].
+///
+/// ```
+/// # use optimum::core::{solver::hook::Empty, stop_criterion::IterCriterion, Problem, Solver};
+/// # fn run<P: Problem, S: Solver<IterCriterion<P>, Empty, P = P>>(solver: &mut S)
+/// # where
+/// #     P::Solution: Clone,
+/// #     P::Value: std::fmt::Display,
+/// # {
+/// let mut stop_criterion = IterCriterion::new(1000);
+///
+/// for incumbent in solver.incumbents(&mut stop_criterion, &mut Empty) {
+///     println!(
+///         "{} at iteration {} after {:?}",
+///         incumbent.evaluation.value(),
+///         incumbent.iteration,
+///         incumbent.elapsed
+///     );
+/// }
+/// # }
+/// ```
+pub struct Incumbents<'a, S, SC, H>
+where
+    S: Solver<SC, H>,
+    SC: StopCriterion<S::P>,
+    H: IterHook<S::P>,
+{
+    solver: &'a mut S,
+    stop_criterion: &'a mut SC,
+    hook: &'a mut H,
+    tracker: Tracker<S::P>,
+    done: bool,
+}
+
+impl<'a, S, SC, H> Incumbents<'a, S, SC, H>
+where
+    S: Solver<SC, H>,
+    SC: StopCriterion<S::P>,
+    H: IterHook<S::P>,
+{
+    /// Prepares the execution of `solver`. Nothing is done until the first call to [next][Iterator::next].
+    pub fn new(solver: &'a mut S, stop_criterion: &'a mut SC, hook: &'a mut H) -> Self {
+        Self {
+            tracker: Tracker::new(solver.objective()),
+            solver,
+            stop_criterion,
+            hook,
+            done: false,
+        }
+    }
+
+    /// The best evaluation found so far.
+    pub fn best(&self) -> Option<&Evaluation<S::P>> {
+        self.tracker.best()
+    }
+
+    /// Gives ownership of the best evaluation found so far.
+    pub fn into_best(self) -> Option<Evaluation<S::P>> {
+        self.tracker.into_best()
+    }
+}
+
+impl<S, SC, H> Iterator for Incumbents<'_, S, SC, H>
+where
+    S: Solver<SC, H>,
+    SC: StopCriterion<S::P>,
+    H: IterHook<S::P>,
+    <S::P as Problem>::Solution: Clone,
+{
+    type Item = Incumbent<S::P>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.done {
+            if self.tracker.best().is_some() && self.stop_criterion.should_stop() {
+                break;
+            }
+
+            if self.tracker.iteration() == 0 {
+                self.stop_criterion.started();
+                self.hook.started();
+            }
+
+            let candidate = match self.solver.iterate(self.stop_criterion, self.hook) {
+                Some(candidate) => candidate,
+                None => break,
+            };
+
+            let evaluations = self.solver.evaluations();
+            if self
+                .tracker
+                .track(candidate, evaluations, self.stop_criterion, self.hook)
+            {
+                return self.tracker.best().map(|best| Incumbent {
+                    evaluation: best.clone(),
+                    iteration: self.tracker.iteration(),
+                    elapsed: self.tracker.elapsed(),
+                });
+            }
+        }
+
+        self.done = true;
+        None
+    }
+}
+
+impl<S, SC, H> FusedIterator for Incumbents<'_, S, SC, H>
+where
+    S: Solver<SC, H>,
+    SC: StopCriterion<S::P>,
+    H: IterHook<S::P>,
+    <S::P as Problem>::Solution: Clone,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::{
+        solver::test_helpers::Replay, stop_criterion::IterCriterion, Evaluation, Solver,
+    };
+
+    use super::*;
+
+    #[derive(Default)]
+    struct Changes(usize);
+
+    impl IterHook<()> for Changes {
+        fn better_changed(&mut self, _: &Evaluation<()>, _: &Evaluation<()>) {
+            self.0 += 1;
+        }
+    }
+
+    #[test]
+    fn only_improvements_are_yielded() {
+        let mut solver = Replay(vec![5, 7, 3, 3, 1, 4].into_iter());
+        let mut stop_criterion = IterCriterion::new(100);
+        let mut hook = Changes::default();
+
+        let mut incumbents = solver.incumbents(&mut stop_criterion, &mut hook);
+        let found = incumbents
+            .by_ref()
+            .map(|it| (it.iteration, it.evaluation.value()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(found, [(1, 5), (3, 3), (5, 1)]);
+        assert_eq!(incumbents.best().map(Evaluation::value), Some(1));
+        assert_eq!(incumbents.next().map(|it| it.iteration), None);
+        assert_eq!(hook.0, 2);
+        assert_eq!(stop_criterion.current_iter(), 6);
+    }
+
+    #[test]
+    fn stop_criterion_is_respected() {
+        let mut solver = Replay(vec![5, 4, 3, 2, 1].into_iter());
+        let mut stop_criterion = IterCriterion::new(2);
+
+        let found = solver
+            .incumbents(&mut stop_criterion, &mut Changes::default())
+            .count();
+
+        assert_eq!(found, 2);
+    }
+}
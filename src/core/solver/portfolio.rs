@@ -0,0 +1,388 @@
+//! Defines [Portfolio], which runs several [Solver]s at once on the same [Problem].
+
+use std::{
+    cell::Cell,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+    thread,
+};
+
+use super::{
+    super::{
+        stop_criterion::SharedCriterion, Comparison, Evaluation, Objective, Problem, StopCriterion,
+    },
+    drive, IterHook, Solver, Tracker,
+};
+
+/// A [Solver] which can take the best solution found by other solvers into account, e.g. by injecting it
+/// into its population or by restarting its search from it.
+pub trait AcceptsIncumbent<P: Problem> {
+    /// Called with a solution better than any this solver has found so far.
+    fn accept_incumbent(&mut self, incumbent: &Evaluation<P>);
+}
+
+/// Runs several solvers for the same problem in separate threads and returns the best solution found
+/// among all of them.
+///
+/// The members share the same stop criterion through a [SharedCriterion], so their iterations are
+/// counted together and they stop at once. Members added with [cooperative][Self::cooperative]
+/// receive each new global best found by the others.
+///
+/// Solvers and hooks are borrowed, so they may be inspected after [solve][Self::solve]. Members are driven
+/// by the default loop of [Solver::solve], so solvers which override it behave as if they didn't.
+///
+/// ```
+/// # use rand::{rngs::StdRng, SeedableRng};
+/// # use optimum::core::{solver::Portfolio, stop_criterion::IterCriterion, Evaluation, Objective, Problem};
+/// # use optimum::metaheuristics::genetic::{brkga::{Brkga, EmptyHook, Params, RandomMemberBuilder}, Decoder, RandomKey};
+/// # struct KeySum;
+/// # impl Problem for KeySum {
+/// #     const OBJECTIVE: Objective = Objective::Min;
+/// #     type Solution = Vec<RandomKey>;
+/// #     type Value = usize;
+/// #     fn objective_function(&self, solution: Vec<RandomKey>) -> Evaluation<Self> {
+/// #         let value = (solution.iter().sum::<f64>() * 1000.0) as usize;
+/// #         Evaluation::new(solution, value)
+/// #     }
+/// # }
+/// # impl Decoder for KeySum {
+/// #     type P = Self;
+/// #     fn decode(&self, member: &[RandomKey]) -> Vec<RandomKey> { member.to_vec() }
+/// #     fn encode(&self, solution: &Vec<RandomKey>) -> Option<Box<[RandomKey]>> { Some(solution.clone().into()) }
+/// #     fn problem(&self) -> &Self { self }
+/// # }
+/// let params = Params::builder(20, 10).build().unwrap();
+/// let mut first = Brkga::new(&KeySum, StdRng::seed_from_u64(0), params, RandomMemberBuilder);
+/// let mut second = Brkga::new(&KeySum, StdRng::seed_from_u64(1), params, RandomMemberBuilder);
+///
+/// let best = Portfolio::new()
+///     .member(&mut first, &mut EmptyHook)
+///     .cooperative(&mut second, &mut EmptyHook)
+///     .solve(IterCriterion::new(100));
+///
+/// assert!(best.unwrap().value() <= first.best().value().min(second.best().value()));
+/// ```
+pub struct Portfolio<'a, P: Problem, SC> {
+    members: Vec<Box<dyn Member<P, SC> + Send + 'a>>,
+}
+
+impl<P: Problem, SC> Default for Portfolio<'_, P, SC> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, P: Problem, SC> Portfolio<'a, P, SC> {
+    /// Creates an empty portfolio.
+    pub fn new() -> Self {
+        Self {
+            members: Vec::new(),
+        }
+    }
+
+    /// Adds a solver which runs on its own.
+    pub fn member<S, H>(mut self, solver: &'a mut S, hook: &'a mut H) -> Self
+    where
+        S: Solver<SharedCriterion<SC>, H, P = P> + Send,
+        H: IterHook<P> + Send,
+        SC: StopCriterion<P>,
+        P::Solution: Clone,
+    {
+        self.members.push(Box::new(Independent { solver, hook }));
+        self
+    }
+
+    /// Adds a solver which receives the incumbents found by the other members.
+    pub fn cooperative<S, H>(mut self, solver: &'a mut S, hook: &'a mut H) -> Self
+    where
+        S: Solver<SharedCriterion<SC>, H, P = P> + AcceptsIncumbent<P> + Send,
+        H: IterHook<P> + Send,
+        SC: StopCriterion<P>,
+        P::Solution: Clone,
+    {
+        self.members.push(Box::new(Cooperative { solver, hook }));
+        self
+    }
+
+    /// Number of solvers in the portfolio.
+    pub fn len(&self) -> usize {
+        self.members.len()
+    }
+
+    /// Tells if no solver was added yet.
+    pub fn is_empty(&self) -> bool {
+        self.members.is_empty()
+    }
+
+    /// Runs every member until `stop_criterion` is met, returning the best solution found.
+    pub fn solve(mut self, stop_criterion: SC) -> Option<Evaluation<P>>
+    where
+        SC: Send,
+        P::Solution: Clone + Send,
+        P::Value: Send,
+    {
+        let board = Board {
+            best: Mutex::new(None),
+            version: AtomicUsize::new(0),
+            objective: self.members.first()?.objective(),
+        };
+        let stop_criterion = SharedCriterion::new(stop_criterion);
+
+        thread::scope(|s| {
+            for member in self.members.iter_mut() {
+                let stop_criterion = stop_criterion.clone();
+                let board = &board;
+                s.spawn(move || member.run(stop_criterion, board));
+            }
+        });
+
+        board.best.into_inner().unwrap()
+    }
+}
+
+/// Where members publish their incumbents.
+struct Board<P: Problem> {
+    best: Mutex<Option<Evaluation<P>>>,
+    /// Increased whenever `best` changes.
+    version: AtomicUsize,
+    objective: Objective,
+}
+
+impl<P: Problem> Board<P>
+where
+    P::Solution: Clone,
+{
+    /// Replaces the global best if `candidate` is better, returning the new version in that case.
+    fn publish(&self, candidate: &Evaluation<P>) -> Option<usize> {
+        let mut best = self.best.lock().unwrap();
+
+        let better = match best.as_ref() {
+            Some(best) => matches!(
+                self.objective.compare(candidate.value(), best.value()),
+                Comparison::Better
+            ),
+            None => true,
+        };
+
+        better.then(|| {
+            *best = Some(candidate.clone());
+            self.version.fetch_add(1, Ordering::AcqRel) + 1
+        })
+    }
+
+    /// The global best and its version, if it changed since `seen`.
+    fn newer_than(&self, seen: usize) -> Option<(usize, Evaluation<P>)> {
+        if self.version.load(Ordering::Acquire) == seen {
+            return None;
+        }
+
+        let best = self.best.lock().unwrap();
+        let version = self.version.load(Ordering::Acquire);
+        best.clone().map(|best| (version, best))
+    }
+}
+
+trait Member<P: Problem, SC> {
+    fn objective(&self) -> Objective;
+
+    fn run(&mut self, stop_criterion: SharedCriterion<SC>, board: &Board<P>);
+}
+
+struct Independent<'a, S, H> {
+    solver: &'a mut S,
+    hook: &'a mut H,
+}
+
+struct Cooperative<'a, S, H> {
+    solver: &'a mut S,
+    hook: &'a mut H,
+}
+
+impl<P, SC, S, H> Member<P, SC> for Independent<'_, S, H>
+where
+    P: Problem,
+    P::Solution: Clone,
+    SC: StopCriterion<P>,
+    S: Solver<SharedCriterion<SC>, H, P = P>,
+    H: IterHook<P>,
+{
+    fn objective(&self) -> Objective {
+        self.solver.objective()
+    }
+
+    fn run(&mut self, stop_criterion: SharedCriterion<SC>, board: &Board<P>) {
+        run(self.solver, self.hook, stop_criterion, board, None);
+    }
+}
+
+impl<P, SC, S, H> Member<P, SC> for Cooperative<'_, S, H>
+where
+    P: Problem,
+    P::Solution: Clone,
+    SC: StopCriterion<P>,
+    S: Solver<SharedCriterion<SC>, H, P = P> + AcceptsIncumbent<P>,
+    H: IterHook<P>,
+{
+    fn objective(&self) -> Objective {
+        self.solver.objective()
+    }
+
+    fn run(&mut self, stop_criterion: SharedCriterion<SC>, board: &Board<P>) {
+        run(
+            self.solver,
+            self.hook,
+            stop_criterion,
+            board,
+            Some(S::accept_incumbent),
+        );
+    }
+}
+
+/// The loop of [Solver::solve], publishing each new best to `board` and passing the ones found by
+/// other members to `accept`, when it's given.
+fn run<P, SC, S, H>(
+    solver: &mut S,
+    hook: &mut H,
+    mut stop_criterion: SharedCriterion<SC>,
+    board: &Board<P>,
+    accept: Option<fn(&mut S, &Evaluation<P>)>,
+) where
+    P: Problem,
+    P::Solution: Clone,
+    SC: StopCriterion<P>,
+    S: Solver<SharedCriterion<SC>, H, P = P>,
+    H: IterHook<P>,
+{
+    // The last version of the board seen by this member.
+    let seen = Cell::new(0);
+
+    let before = |solver: &mut S, tracker: &Tracker<P>| {
+        if let (Some(accept), Some(best)) = (accept, tracker.best()) {
+            if let Some((version, incumbent)) = board.newer_than(seen.get()) {
+                seen.set(version);
+                if tracker.is_better(&incumbent, best) {
+                    accept(solver, &incumbent);
+                }
+            }
+        }
+    };
+
+    let improved = |best: &Evaluation<P>| {
+        if let Some(version) = board.publish(best) {
+            seen.set(version);
+        }
+    };
+
+    drive(solver, &mut stop_criterion, hook, before, improved);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use crate::core::stop_criterion::{CancellationToken, IterCriterion};
+
+    use super::*;
+
+    /// Generates decreasing values until `until` is reached.
+    struct Countdown {
+        next: usize,
+        until: usize,
+    }
+
+    impl<SC: StopCriterion<()>, H: IterHook<()>> Solver<SC, H> for Countdown {
+        type P = ();
+
+        fn iterate(&mut self, _: &mut SC, _: &mut H) -> Option<Evaluation<()>> {
+            (self.next >= self.until).then(|| {
+                self.next -= 1;
+                Evaluation::new((), self.next + 1)
+            })
+        }
+    }
+
+    /// Never finds anything good by itself, so it waits for incumbents.
+    struct Follower {
+        accepted: Vec<usize>,
+        target: usize,
+        token: CancellationToken,
+    }
+
+    impl<SC: StopCriterion<()>, H: IterHook<()>> Solver<SC, H> for Follower {
+        type P = ();
+
+        fn iterate(&mut self, _: &mut SC, _: &mut H) -> Option<Evaluation<()>> {
+            thread::yield_now();
+            Some(Evaluation::new((), usize::MAX))
+        }
+    }
+
+    impl AcceptsIncumbent<()> for Follower {
+        fn accept_incumbent(&mut self, incumbent: &Evaluation<()>) {
+            self.accepted.push(incumbent.value());
+            if incumbent.value() == self.target {
+                self.token.cancel();
+            }
+        }
+    }
+
+    #[derive(Default)]
+    struct Iterations(usize);
+
+    impl IterHook<()> for Iterations {
+        fn iterated(&mut self, _: &Evaluation<()>) {
+            self.0 += 1;
+        }
+    }
+
+    #[test]
+    fn best_among_members_is_returned() {
+        let mut first = Countdown {
+            next: 100,
+            until: 0,
+        };
+        let mut second = Countdown { next: 50, until: 0 };
+        let (mut first_hook, mut second_hook) = (Iterations::default(), Iterations::default());
+
+        let best = Portfolio::new()
+            .member(&mut first, &mut first_hook)
+            .member(&mut second, &mut second_hook)
+            .solve(IterCriterion::new(20));
+
+        // Every member iterates at least once, as in `Solver::solve`.
+        assert!(first_hook.0 + second_hook.0 >= 20);
+        assert_eq!(best.map(|it| it.value()), Some(50 - (second_hook.0 - 1)));
+    }
+
+    #[test]
+    fn incumbents_are_broadcast() {
+        let token = CancellationToken::new();
+        let mut leader = Countdown {
+            next: 100,
+            until: 90,
+        };
+        let mut follower = Follower {
+            accepted: Vec::new(),
+            target: 90,
+            token: token.clone(),
+        };
+
+        let best = Portfolio::new()
+            .member(&mut leader, &mut crate::core::solver::hook::Empty)
+            .cooperative(&mut follower, &mut crate::core::solver::hook::Empty)
+            .solve(token);
+
+        assert_eq!(best.map(|it| it.value()), Some(90));
+        assert_eq!(follower.accepted.last(), Some(&90));
+        assert!(follower.accepted.windows(2).all(|w| w[0] > w[1]));
+    }
+
+    #[test]
+    fn empty_portfolios_find_nothing() {
+        let portfolio = Portfolio::<(), IterCriterion<()>>::new();
+        assert!(portfolio.is_empty());
+        assert!(portfolio.solve(IterCriterion::new(1)).is_none());
+    }
+}
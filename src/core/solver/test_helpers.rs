@@ -0,0 +1,14 @@
+use crate::core::{Evaluation, Solver, StopCriterion};
+
+use super::IterHook;
+
+/// Generates the given values in order, for the problem `()`.
+pub(crate) struct Replay(pub(crate) std::vec::IntoIter<usize>);
+
+impl<SC: StopCriterion<()>, H: IterHook<()>> Solver<SC, H> for Replay {
+    type P = ();
+
+    fn iterate(&mut self, _: &mut SC, _: &mut H) -> Option<Evaluation<()>> {
+        self.0.next().map(|value| Evaluation::new((), value))
+    }
+}
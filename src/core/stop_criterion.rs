@@ -4,30 +4,80 @@
 //! - [TimeCriterion]: stops after a given time duration.
 //! - [QualityCriterion]: stops after a solution achieves the minimum desired quality.
 //! - [ImprovementCriterion]: stops after no more improvements have been performed for a given number of iterations.
+//! - [GapCriterion]: stops after a solution gets within a given gap of a known bound.
+//! - [EvaluationCriterion]: stops after a number of evaluations of the objective function.
+//! - [CpuTimeCriterion]: stops after the process uses a given amount of CPU time.
+//! - [CancellationToken]: stops when it's cancelled, possibly from another thread.
+//! - [SharedCriterion]: shares a criterion among solvers running in separate threads.
 //!
-//! Additionally, there's the [CriterionCombiner], which allows to combine two [StopCriterion]s
-//! into one and stops as soon as either of them stops.
+//! Additionally, criteria may be combined: [Any] stops as soon as one of its criteria stops, [All] once every
+//! one of them stops and [Not] while its criterion doesn't. [StopCriterionExt] builds them through methods, e.g.
+//! `min_iterations.and(time.or(target))`. [CriterionCombiner] is the original combination of two criteria, which
+//! stops as soon as either of them stops.
 
 use num_traits::real::Real;
 
+mod cancellation_token;
+mod clock;
+mod cpu_time_criterion;
+mod evaluation_criterion;
+mod gap_criterion;
 mod improvement_criterion;
 mod iter_criterion;
 mod quality_criterion;
+mod shared_criterion;
 mod time_criterion;
 
+mod combinators;
 mod criterion_combiner;
 
 #[cfg(test)]
 pub(crate) mod test_helpers;
 
+pub use cancellation_token::CancellationToken;
+pub use clock::{Clock, CpuClock, ManualClock, SystemClock};
+pub use cpu_time_criterion::CpuTimeCriterion;
+pub use evaluation_criterion::EvaluationCriterion;
+pub use gap_criterion::GapCriterion;
 pub use improvement_criterion::ImprovementCriterion;
 pub use iter_criterion::IterCriterion;
 pub use quality_criterion::QualityCriterion;
+pub use shared_criterion::SharedCriterion;
 pub use time_criterion::TimeCriterion;
 
+pub use combinators::{Aggregation, All, Any, Criteria, Not, StopCriterionExt};
 pub use criterion_combiner::CriterionCombiner;
 
-use super::Problem;
+use std::time::Duration;
+
+use super::{Evaluation, Problem};
+
+/// What a [StopCriterion] gets to know about each iteration through [update][StopCriterion::update].
+pub struct Context<'a, P: Problem> {
+    /// The evaluation generated by the iteration.
+    pub candidate: &'a Evaluation<P>,
+    /// The value of the best evaluation found so far, which is `candidate` itself when it
+    /// [improved][Self::improved] the best.
+    pub best: P::Value,
+    /// Whether `candidate` became the best evaluation. It's always true in the first iteration.
+    pub improved: bool,
+    /// Time since the solver started.
+    ///
+    /// It's always measured on the wall clock, through [Instant][std::time::Instant], whatever the [Clock] of
+    /// any criterion. Criteria which must be deterministic in tests measure time through their own [Clock],
+    /// as [TimeCriterion] does.
+    pub elapsed: Duration,
+    /// The number of the iteration, starting from one.
+    pub iteration: usize,
+}
+
+impl<P: Problem> Clone for Context<'_, P> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<P: Problem> Copy for Context<'_, P> {}
 
 /// A stop criterion determines when the [Solver][crate::core::Solver] should stop seeking better solutions
 /// and just yield the results. The implementations provided in [core::stop_criterion][crate::core::stop_criterion] should cover
@@ -41,10 +91,21 @@ pub trait StopCriterion<P: Problem, R: Real = f64> {
         self.progress() >= R::one()
     }
 
-    /// Updates the internal state. `new_value` is the value's newly generated solution.
+    /// Tells that the solver is about to run its first iteration, e.g. to start a timer.
+    ///
+    /// [Solver::solve][crate::core::Solver::solve] calls it before iterating. It does nothing by default.
+    fn started(&mut self) {}
+
+    /// Updates the internal state with what happened in the iteration described by `context`.
     ///
     /// Must be called at the end of each iteration.
-    fn update(&mut self, new_value: P::Value);
+    fn update(&mut self, context: Context<'_, P>);
+
+    /// Tells that `count` more evaluations of the objective function were performed since the last call.
+    ///
+    /// [Solver::solve][crate::core::Solver::solve] calls it before [update][Self::update] with the
+    /// [evaluations][crate::core::Solver::evaluations] done by the iteration. It does nothing by default.
+    fn evaluated(&mut self, _count: usize) {}
 
     /// This is basically how many times [update][Self::update] was called.
     fn current_iter(&self) -> usize;
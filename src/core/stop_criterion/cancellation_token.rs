@@ -0,0 +1,140 @@
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+
+use crate::core::{stop_criterion::Context, Problem, StopCriterion};
+
+/// Stops as soon as [cancel][CancellationToken::cancel] is called on any of its clones.
+///
+/// Clones share the cancellation, so one of them can be given to the solver while the others stay with
+/// whoever may want to stop it, such as a Ctrl-C handler or another thread. Solvers finish the current
+/// iteration and return the best solution found, just as with any other criterion.
+///
+/// It's usually combined with another criterion through [CriterionCombiner][super::CriterionCombiner].
+///
+/// ```
+/// # use std::thread;
+/// # use optimum::core::{stop_criterion::{CancellationToken, CriterionCombiner, IterCriterion}, StopCriterion};
+/// # use optimum::core::{Evaluation, Objective, Problem};
+/// # struct P;
+/// # impl Problem for P {
+/// #     const OBJECTIVE: Objective = Objective::Min;
+/// #     type Solution = ();
+/// #     type Value = usize;
+/// #     fn objective_function(&self, _: ()) -> Evaluation<Self> { Evaluation::new((), 0) }
+/// # }
+/// let token = CancellationToken::new();
+/// let mut stop_criterion = CriterionCombiner::<P, _, _>::new(IterCriterion::new(1000), token.clone());
+///
+/// thread::spawn(move || token.cancel()).join().unwrap();
+///
+/// assert!(stop_criterion.should_stop());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+    current_iter: usize,
+}
+
+impl CancellationToken {
+    /// Creates a token which isn't cancelled yet.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Asks every solver using this token, or one of its clones, to stop.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Release);
+    }
+
+    /// Tells if [cancel][Self::cancel] was called.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Acquire)
+    }
+}
+
+impl<P: Problem> StopCriterion<P> for CancellationToken {
+    fn progress(&self) -> f64 {
+        if self.is_cancelled() {
+            1.0
+        } else {
+            0.0
+        }
+    }
+
+    fn update(&mut self, _: Context<'_, P>) {
+        self.current_iter += 1;
+    }
+
+    fn current_iter(&self) -> usize {
+        self.current_iter
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use crate::core::{
+        solver::IterHook,
+        stop_criterion::{CriterionCombiner, IterCriterion},
+        Evaluation, Solver,
+    };
+
+    use super::*;
+    use crate::core::stop_criterion::test_helpers::update;
+
+    #[test]
+    fn clones_share_the_cancellation() {
+        let mut token = CancellationToken::new();
+        let handle = token.clone();
+
+        update(&mut token, 0);
+        assert!(!StopCriterion::<()>::should_stop(&token));
+
+        thread::spawn(move || handle.cancel()).join().unwrap();
+
+        assert!(token.is_cancelled());
+        assert!(StopCriterion::<()>::should_stop(&token));
+        assert_eq!(StopCriterion::<()>::current_iter(&token), 1);
+    }
+
+    /// Generates decreasing values forever.
+    struct Countdown(usize);
+
+    impl<SC: StopCriterion<()>, H: IterHook<()>> Solver<SC, H> for Countdown {
+        type P = ();
+
+        fn iterate(&mut self, _: &mut SC, _: &mut H) -> Option<Evaluation<()>> {
+            self.0 -= 1;
+            Some(Evaluation::new((), self.0))
+        }
+    }
+
+    /// Cancels the execution once `at` is reached.
+    struct CancelAt {
+        at: usize,
+        token: CancellationToken,
+    }
+
+    impl IterHook<()> for CancelAt {
+        fn iterated(&mut self, new: &Evaluation<()>) {
+            if new.value() == self.at {
+                self.token.cancel();
+            }
+        }
+    }
+
+    #[test]
+    fn cancelled_solvers_return_the_incumbent() {
+        let token = CancellationToken::new();
+        let mut stop_criterion = CriterionCombiner::new(IterCriterion::new(1000), token.clone());
+        let mut hook = CancelAt { at: 90, token };
+
+        let best = Countdown(100).solve(&mut stop_criterion, &mut hook);
+
+        assert_eq!(best.map(|it| it.value()), Some(90));
+        assert_eq!(stop_criterion.current_iter(), 10);
+    }
+}
@@ -0,0 +1,82 @@
+use std::{
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use cpu_time::ProcessTime;
+
+/// A source of time for time-based criteria, such as [TimeCriterion][super::TimeCriterion].
+///
+/// [SystemClock] is the wall clock used by default and [CpuClock] measures CPU time, while [ManualClock]
+/// only moves when told to, so time-based criteria and solvers may be tested without sleeping.
+pub trait Clock {
+    /// The time passed since a fixed, arbitrary point of this clock.
+    fn now(&self) -> Duration;
+}
+
+/// The monotonic wall clock of the system, i.e. [Instant].
+#[derive(Debug, Clone, Copy)]
+pub struct SystemClock {
+    origin: Instant,
+}
+
+impl Default for SystemClock {
+    fn default() -> Self {
+        Self {
+            origin: Instant::now(),
+        }
+    }
+}
+
+impl Clock for SystemClock {
+    fn now(&self) -> Duration {
+        self.origin.elapsed()
+    }
+}
+
+/// The CPU time used by the process, counting every one of its threads.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CpuClock;
+
+impl Clock for CpuClock {
+    fn now(&self) -> Duration {
+        ProcessTime::now().as_duration()
+    }
+}
+
+/// A clock which only moves through [advance][ManualClock::advance].
+///
+/// Clones share the same time, so one of them can be given to a criterion while the test advances another.
+///
+/// ```
+/// # use std::time::Duration;
+/// # use optimum::core::stop_criterion::{Clock, ManualClock};
+/// let clock = ManualClock::new();
+/// let handle = clock.clone();
+///
+/// handle.advance(Duration::from_secs(5));
+///
+/// assert_eq!(clock.now(), Duration::from_secs(5));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ManualClock {
+    now: Arc<Mutex<Duration>>,
+}
+
+impl ManualClock {
+    /// Creates a clock at time zero.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Moves the clock, and all of its clones, forward by `duration`.
+    pub fn advance(&self, duration: Duration) {
+        *self.now.lock().unwrap() += duration;
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> Duration {
+        *self.now.lock().unwrap()
+    }
+}
@@ -0,0 +1,400 @@
+use std::marker::PhantomData;
+
+use crate::core::{stop_criterion::Context, Problem, StopCriterion};
+
+/// A group of criteria combined by [All] or [Any].
+///
+/// It's implemented for tuples of up to six criteria and for [Vec]s, including
+/// `Vec<Box<dyn StopCriterion<P>>>` for groups only known at runtime.
+pub trait Criteria<P: Problem> {
+    /// Calls `f` with each criterion in order.
+    fn for_each(&self, f: &mut dyn FnMut(&dyn StopCriterion<P>));
+
+    /// Calls `f` with each criterion in order, allowing them to be updated.
+    fn for_each_mut(&mut self, f: &mut dyn FnMut(&mut dyn StopCriterion<P>));
+
+    /// Number of criteria in the group.
+    fn count(&self) -> usize {
+        let mut count = 0;
+        self.for_each(&mut |_| count += 1);
+        count
+    }
+}
+
+impl<P: Problem, C: StopCriterion<P>> Criteria<P> for Vec<C> {
+    fn for_each(&self, f: &mut dyn FnMut(&dyn StopCriterion<P>)) {
+        self.iter().for_each(|criterion| f(criterion));
+    }
+
+    fn for_each_mut(&mut self, f: &mut dyn FnMut(&mut dyn StopCriterion<P>)) {
+        self.iter_mut().for_each(|criterion| f(criterion));
+    }
+
+    fn count(&self) -> usize {
+        self.len()
+    }
+}
+
+macro_rules! impl_criteria_for_tuples {
+    ($(($($name:ident $idx:tt),+)),+) => {
+        $(
+            impl<P: Problem, $($name: StopCriterion<P>),+> Criteria<P> for ($($name,)+) {
+                fn for_each(&self, f: &mut dyn FnMut(&dyn StopCriterion<P>)) {
+                    $(f(&self.$idx);)+
+                }
+
+                fn for_each_mut(&mut self, f: &mut dyn FnMut(&mut dyn StopCriterion<P>)) {
+                    $(f(&mut self.$idx);)+
+                }
+            }
+        )+
+    };
+}
+
+impl_criteria_for_tuples!(
+    (A 0, B 1),
+    (A 0, B 1, C 2),
+    (A 0, B 1, C 2, D 3),
+    (A 0, B 1, C 2, D 3, E 4),
+    (A 0, B 1, C 2, D 3, E 4, F 5)
+);
+
+/// How the [progress][StopCriterion::progress] of [All] and [Any] is computed from the progress of their criteria.
+///
+/// It's only reported: whether they stop depends on the criteria stopping, not on the aggregated progress.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Aggregation {
+    /// The highest progress. It's the default of [Any].
+    Max,
+    /// The lowest progress. It's the default of [All].
+    Min,
+    /// The average of the progresses weighted by the given weights, one per criterion.
+    Weighted(Vec<f64>),
+}
+
+impl Aggregation {
+    fn aggregate<P: Problem>(&self, criteria: &impl Criteria<P>) -> f64 {
+        let mut index = 0;
+        let mut result = match self {
+            Aggregation::Max => f64::NEG_INFINITY,
+            Aggregation::Min => f64::INFINITY,
+            Aggregation::Weighted(_) => 0.0,
+        };
+
+        criteria.for_each(&mut |criterion| {
+            let progress = criterion.progress();
+            result = match self {
+                Aggregation::Max => result.max(progress),
+                Aggregation::Min => result.min(progress),
+                Aggregation::Weighted(weights) => result + weights[index] * progress,
+            };
+            index += 1;
+        });
+
+        match self {
+            _ if index == 0 => 0.0,
+            Aggregation::Weighted(weights) => result / weights.iter().sum::<f64>(),
+            _ => result,
+        }
+    }
+
+    fn check<P: Problem>(&self, criteria: &impl Criteria<P>) {
+        if let Aggregation::Weighted(weights) = self {
+            assert_eq!(
+                weights.len(),
+                criteria.count(),
+                "there must be one weight per criterion"
+            );
+            assert!(
+                weights.iter().all(|&weight| weight >= 0.0) && weights.iter().sum::<f64>() > 0.0,
+                "weights must be non-negative and not all zero"
+            );
+        }
+    }
+}
+
+macro_rules! combinator {
+    ($(#[$doc:meta])* $name:ident, $default:ident, $empty:literal, $combine:tt) => {
+        $(#[$doc])*
+        #[derive(Debug)]
+        pub struct $name<P, C> {
+            criteria: C,
+            aggregation: Aggregation,
+            current_iter: usize,
+            _p: PhantomData<P>,
+        }
+
+        impl<P, C: Clone> Clone for $name<P, C> {
+            fn clone(&self) -> Self {
+                Self {
+                    criteria: self.criteria.clone(),
+                    aggregation: self.aggregation.clone(),
+                    current_iter: self.current_iter,
+                    _p: PhantomData,
+                }
+            }
+        }
+
+        impl<P: Problem, C: Criteria<P>> $name<P, C> {
+            #[doc = concat!("Combines `criteria`, aggregating their progress with [Aggregation::", stringify!($default), "].")]
+            pub fn new(criteria: C) -> Self {
+                Self {
+                    criteria,
+                    aggregation: Aggregation::$default,
+                    current_iter: 0,
+                    _p: PhantomData,
+                }
+            }
+
+            /// Changes how the progress is aggregated.
+            ///
+            /// # Panics
+            ///
+            /// If the aggregation is [weighted][Aggregation::Weighted] and there isn't one non-negative weight
+            /// per criterion, or all of them are zero.
+            pub fn with_aggregation(mut self, aggregation: Aggregation) -> Self {
+                aggregation.check(&self.criteria);
+                self.aggregation = aggregation;
+                self
+            }
+
+            /// Get a reference to the combined criteria.
+            pub fn criteria(&self) -> &C {
+                &self.criteria
+            }
+        }
+
+        impl<P: Problem, C: Criteria<P>> StopCriterion<P> for $name<P, C> {
+            fn progress(&self) -> f64 {
+                self.aggregation.aggregate(&self.criteria)
+            }
+
+            fn should_stop(&self) -> bool {
+                let mut stops = $empty;
+                self.criteria
+                    .for_each(&mut |criterion| stops = stops $combine criterion.should_stop());
+                stops
+            }
+
+            fn started(&mut self) {
+                self.criteria.for_each_mut(&mut |criterion| criterion.started());
+            }
+
+            fn update(&mut self, context: Context<'_, P>) {
+                self.criteria
+                    .for_each_mut(&mut |criterion| criterion.update(context));
+                self.current_iter += 1;
+            }
+
+            fn evaluated(&mut self, count: usize) {
+                self.criteria
+                    .for_each_mut(&mut |criterion| criterion.evaluated(count));
+            }
+
+            fn current_iter(&self) -> usize {
+                self.current_iter
+            }
+        }
+    };
+}
+
+combinator!(
+    /// Stops once every one of its criteria stops, e.g. "at least 1000 iterations" and "a time limit".
+    ///
+    /// An empty group stops right away.
+    All,
+    Min,
+    true,
+    &&
+);
+
+combinator!(
+    /// Stops as soon as any of its criteria stops, e.g. "a time limit" or "a target value".
+    ///
+    /// An empty group never stops.
+    Any,
+    Max,
+    false,
+    ||
+);
+
+/// Stops while its criterion doesn't, e.g. to express "unless" in an [All].
+///
+/// Its progress is either zero or one.
+#[derive(Debug)]
+pub struct Not<P, C> {
+    criterion: C,
+    _p: PhantomData<P>,
+}
+
+impl<P, C: Clone> Clone for Not<P, C> {
+    fn clone(&self) -> Self {
+        Self {
+            criterion: self.criterion.clone(),
+            _p: PhantomData,
+        }
+    }
+}
+
+impl<P: Problem, C: StopCriterion<P>> Not<P, C> {
+    /// Negates `criterion`.
+    pub fn new(criterion: C) -> Self {
+        Self {
+            criterion,
+            _p: PhantomData,
+        }
+    }
+}
+
+impl<P: Problem, C: StopCriterion<P>> StopCriterion<P> for Not<P, C> {
+    fn progress(&self) -> f64 {
+        if self.should_stop() {
+            1.0
+        } else {
+            0.0
+        }
+    }
+
+    fn should_stop(&self) -> bool {
+        !self.criterion.should_stop()
+    }
+
+    fn started(&mut self) {
+        self.criterion.started();
+    }
+
+    fn update(&mut self, context: Context<'_, P>) {
+        self.criterion.update(context);
+    }
+
+    fn evaluated(&mut self, count: usize) {
+        self.criterion.evaluated(count);
+    }
+
+    fn current_iter(&self) -> usize {
+        self.criterion.current_iter()
+    }
+}
+
+impl<P: Problem, C: StopCriterion<P> + ?Sized> StopCriterion<P> for Box<C> {
+    fn progress(&self) -> f64 {
+        (**self).progress()
+    }
+
+    fn should_stop(&self) -> bool {
+        (**self).should_stop()
+    }
+
+    fn started(&mut self) {
+        (**self).started();
+    }
+
+    fn update(&mut self, context: Context<'_, P>) {
+        (**self).update(context);
+    }
+
+    fn evaluated(&mut self, count: usize) {
+        (**self).evaluated(count);
+    }
+
+    fn current_iter(&self) -> usize {
+        (**self).current_iter()
+    }
+}
+
+/// Combines criteria through methods, so `a.and(b.or(c))` reads as the condition to stop.
+pub trait StopCriterionExt<P: Problem>: StopCriterion<P> + Sized {
+    /// Stops once both `self` and `other` stop.
+    fn and<O: StopCriterion<P>>(self, other: O) -> All<P, (Self, O)> {
+        All::new((self, other))
+    }
+
+    /// Stops as soon as either `self` or `other` stops.
+    fn or<O: StopCriterion<P>>(self, other: O) -> Any<P, (Self, O)> {
+        Any::new((self, other))
+    }
+
+    /// Stops while `self` doesn't.
+    fn not(self) -> Not<P, Self> {
+        Not::new(self)
+    }
+}
+
+impl<P: Problem, T: StopCriterion<P>> StopCriterionExt<P> for T {}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::core::stop_criterion::{IterCriterion, QualityCriterion, TimeCriterion};
+
+    use super::*;
+    use crate::core::stop_criterion::test_helpers::update;
+
+    #[test]
+    fn minimum_iterations_and_time_or_target() {
+        let mut criterion = IterCriterion::<()>::new(5)
+            .and(TimeCriterion::new(Duration::from_secs(3600)).or(QualityCriterion::new(10)));
+
+        // The target is reached before the minimum number of iterations.
+        for _ in 0..4 {
+            update(&mut criterion, 10);
+            assert!(!criterion.should_stop());
+        }
+        update(&mut criterion, 10);
+
+        assert!(criterion.should_stop());
+        assert_eq!(criterion.current_iter(), 5);
+    }
+
+    #[test]
+    fn not_inverts_the_criterion() {
+        let mut criterion = IterCriterion::<()>::new(1).not();
+
+        assert!(criterion.should_stop());
+        update(&mut criterion, 0);
+        assert!(!criterion.should_stop());
+        assert_eq!(criterion.progress(), 0.0);
+    }
+
+    #[test]
+    fn progress_is_aggregated() {
+        let criteria = || (IterCriterion::<()>::new(2), IterCriterion::<()>::new(4));
+        let mut max = Any::new(criteria());
+        let mut min = All::new(criteria());
+        let mut weighted =
+            All::new(criteria()).with_aggregation(Aggregation::Weighted(vec![1.0, 3.0]));
+
+        update(&mut max, 0);
+        update(&mut min, 0);
+        update(&mut weighted, 0);
+
+        assert_eq!(max.progress(), 0.5);
+        assert_eq!(min.progress(), 0.25);
+        assert_eq!(weighted.progress(), (0.5 + 3.0 * 0.25) / 4.0);
+    }
+
+    #[test]
+    fn groups_may_be_built_at_runtime() {
+        let criteria: Vec<Box<dyn StopCriterion<()>>> = vec![
+            Box::new(IterCriterion::new(3)),
+            Box::new(QualityCriterion::new(0)),
+        ];
+        let mut any = Any::new(criteria);
+
+        update(&mut any, 1);
+        assert!(!any.should_stop());
+        update(&mut any, 0);
+        assert!(any.should_stop());
+
+        assert!(All::<(), Vec<IterCriterion<()>>>::new(vec![]).should_stop());
+        assert!(!Any::<(), Vec<IterCriterion<()>>>::new(vec![]).should_stop());
+    }
+
+    #[test]
+    #[should_panic]
+    fn weights_must_match_the_criteria() {
+        let criteria = (IterCriterion::<()>::new(2), IterCriterion::<()>::new(4));
+        let _ = Any::new(criteria).with_aggregation(Aggregation::Weighted(vec![1.0]));
+    }
+}
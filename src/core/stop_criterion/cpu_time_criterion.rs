@@ -0,0 +1,130 @@
+use std::time::Duration;
+
+use crate::core::{stop_criterion::Context, Problem, StopCriterion};
+
+use super::{CpuClock, TimeCriterion};
+
+/// This criterion is based on the CPU time used by the process, unlike the wall-clock [TimeCriterion].
+///
+/// Time waiting for other processes doesn't count, so the results are more stable on busy machines. On the other hand,
+/// the time of every thread counts, e.g. when decoding in parallel.
+///
+/// It's a [TimeCriterion] measured by the [CpuClock], so it has the same lifecycle: it's idle until
+/// [started][CpuTimeCriterion::start], which [Solver::solve][crate::core::Solver::solve] does, or until it's
+/// first checked or updated, and clones keep the state of the timer.
+pub struct CpuTimeCriterion<P> {
+    timer: TimeCriterion<P, CpuClock>,
+}
+
+impl<P> std::fmt::Debug for CpuTimeCriterion<P> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CpuTimeCriterion")
+            .field("timer", &self.timer)
+            .finish()
+    }
+}
+
+impl<P> Clone for CpuTimeCriterion<P> {
+    fn clone(&self) -> Self {
+        Self {
+            timer: self.timer.clone(),
+        }
+    }
+}
+
+impl<P> CpuTimeCriterion<P> {
+    /// Creates a new CPU time criterion.
+    ///
+    /// The timer is idle until [started][Self::start], or until it's first checked or updated.
+    pub fn new(duration: Duration) -> Self {
+        Self {
+            timer: TimeCriterion::with_clock(duration, CpuClock),
+        }
+    }
+
+    /// Starts the timer, or resumes it if it was paused. Does nothing if it's already running.
+    pub fn start(&mut self) {
+        self.timer.start();
+    }
+
+    /// Stops counting time until the timer is [started][Self::start] again.
+    pub fn pause(&mut self) {
+        self.timer.pause();
+    }
+
+    /// Brings the criterion back to its initial state: idle, with no time or iterations counted.
+    pub fn reset(&mut self) {
+        self.timer.reset();
+    }
+
+    /// Tells if the timer is counting time.
+    pub fn is_running(&self) -> bool {
+        self.timer.is_running()
+    }
+
+    /// The CPU time counted so far.
+    pub fn elapsed(&self) -> Duration {
+        self.timer.elapsed()
+    }
+}
+
+impl<P: Problem> StopCriterion<P> for CpuTimeCriterion<P> {
+    fn progress(&self) -> f64 {
+        self.timer.progress()
+    }
+
+    fn started(&mut self) {
+        self.start();
+    }
+
+    fn update(&mut self, context: Context<'_, P>) {
+        self.timer.update(context);
+    }
+
+    fn current_iter(&self) -> usize {
+        StopCriterion::<P>::current_iter(&self.timer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{hint::black_box, thread::sleep};
+
+    use super::*;
+    use crate::core::stop_criterion::test_helpers::update;
+
+    #[test]
+    fn it_works() {
+        let mut stop = CpuTimeCriterion::<()>::new(Duration::from_secs(10));
+        stop.start();
+
+        // Sleeping takes no CPU time, even if other tests may take a bit meanwhile.
+        sleep(Duration::from_millis(100));
+        update(&mut stop, 0);
+        assert!(!stop.should_stop());
+
+        let mut stop = CpuTimeCriterion::<()>::new(Duration::from_millis(20));
+        stop.start();
+        while !stop.should_stop() {
+            black_box((0..1000).sum::<u64>());
+            update(&mut stop, 0);
+        }
+        assert!(stop.current_iter() > 0);
+    }
+
+    #[test]
+    fn clones_keep_the_timer() {
+        let mut stop = CpuTimeCriterion::<()>::new(Duration::from_secs(10));
+
+        let idle = stop.clone();
+        stop.start();
+        while stop.elapsed().is_zero() {
+            black_box((0..1000).sum::<u64>());
+        }
+        stop.pause();
+
+        assert!(!idle.is_running());
+        assert_eq!(idle.elapsed(), Duration::ZERO);
+        assert_eq!(stop.clone().elapsed(), stop.elapsed());
+    }
+}
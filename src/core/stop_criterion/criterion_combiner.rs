@@ -1,4 +1,4 @@
-use super::StopCriterion;
+use super::{Context, StopCriterion};
 use crate::core::Problem;
 
 use std::marker::PhantomData;
@@ -46,9 +46,19 @@ where
         }
     }
 
-    fn update(&mut self, new_value: P::Value) {
-        self.a.update(new_value);
-        self.b.update(new_value);
+    fn started(&mut self) {
+        self.a.started();
+        self.b.started();
+    }
+
+    fn update(&mut self, context: Context<'_, P>) {
+        self.a.update(context);
+        self.b.update(context);
+    }
+
+    fn evaluated(&mut self, count: usize) {
+        self.a.evaluated(count);
+        self.b.evaluated(count);
     }
 
     fn current_iter(&self) -> usize {
@@ -59,15 +69,16 @@ where
 
 #[cfg(test)]
 mod tests {
-    use std::{thread::sleep, time::Duration};
+    use std::time::Duration;
 
     use crate::core::stop_criterion::*;
 
     use super::*;
+    use crate::core::stop_criterion::test_helpers::update;
 
-    fn setup<P: Problem>(iter: usize, time: Duration) -> impl StopCriterion<P> {
+    fn setup<P: Problem>(iter: usize, time: Duration, clock: ManualClock) -> impl StopCriterion<P> {
         let iter = IterCriterion::<P>::new(iter);
-        let time = TimeCriterion::<P>::new(time);
+        let time = TimeCriterion::<P, _>::with_clock(time, clock);
 
         CriterionCombiner::new(iter, time)
     }
@@ -75,24 +86,25 @@ mod tests {
     #[test]
     fn iter_works() {
         let max_iters = 5;
-        let mut stop_criterion = setup::<()>(max_iters, Duration::MAX);
+        let mut stop_criterion = setup::<()>(max_iters, Duration::MAX, ManualClock::new());
 
         (0..max_iters).for_each(|i| {
             assert!(!stop_criterion.should_stop());
-            stop_criterion.update(i);
+            update(&mut stop_criterion, i);
         });
 
         assert!(stop_criterion.should_stop());
     }
 
     #[test]
-    #[ignore]
-    // This test is ignore because it fails in CI. Troubled times.
     fn time_works() {
-        let stop_criterion = setup::<()>(usize::MAX, Duration::from_nanos(1));
+        let clock = ManualClock::new();
+        let mut stop_criterion = setup::<()>(usize::MAX, Duration::from_nanos(1), clock.clone());
+
+        stop_criterion.started();
+        assert!(!stop_criterion.should_stop());
 
-        // Ensure that the required time will pass.
-        sleep(Duration::from_millis(10));
+        clock.advance(Duration::from_nanos(1));
 
         assert!(stop_criterion.should_stop());
     }
@@ -0,0 +1,102 @@
+use std::marker::PhantomData;
+
+use crate::core::{stop_criterion::Context, Problem, StopCriterion};
+
+/// Stops after a number of evaluations of the objective function.
+///
+/// Unlike iterations, evaluations are comparable between solvers, e.g. BRKGAs with different population
+/// sizes. The solver must keep track of its [evaluations][crate::core::Solver::evaluations], as the genetic
+/// algorithms do by counting the members decoded and local searches by counting the neighbors evaluated.
+///
+/// # Panics
+///
+/// On [update][StopCriterion::update], if no evaluation was reported yet, as it would never stop.
+#[derive(Debug)]
+pub struct EvaluationCriterion<P> {
+    current_iter: usize,
+    evaluations: usize,
+    max_evaluations: usize,
+    _p: PhantomData<P>,
+}
+
+impl<P> Clone for EvaluationCriterion<P> {
+    fn clone(&self) -> Self {
+        Self {
+            current_iter: self.current_iter,
+            evaluations: self.evaluations,
+            max_evaluations: self.max_evaluations,
+            _p: self._p,
+        }
+    }
+}
+
+impl<P: Problem> EvaluationCriterion<P> {
+    /// Creates a new evaluation criterion.
+    ///
+    /// Stops as soon as `max_evaluations` evaluations are performed.
+    pub fn new(max_evaluations: usize) -> Self {
+        assert!(max_evaluations > 0);
+
+        Self {
+            current_iter: 0,
+            evaluations: 0,
+            max_evaluations,
+            _p: PhantomData,
+        }
+    }
+
+    /// Number of evaluations performed so far.
+    pub fn evaluations(&self) -> usize {
+        self.evaluations
+    }
+}
+
+impl<P: Problem> StopCriterion<P> for EvaluationCriterion<P> {
+    fn progress(&self) -> f64 {
+        self.evaluations as f64 / self.max_evaluations as f64
+    }
+
+    fn update(&mut self, _: Context<'_, P>) {
+        assert!(
+            self.evaluations > 0,
+            "the solver doesn't report its evaluations, so they would never reach the limit"
+        );
+        self.current_iter += 1;
+    }
+
+    fn evaluated(&mut self, count: usize) {
+        self.evaluations += count;
+    }
+
+    fn current_iter(&self) -> usize {
+        self.current_iter
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::stop_criterion::test_helpers::update;
+
+    #[test]
+    fn it_works() {
+        let mut stop = EvaluationCriterion::<()>::new(10);
+
+        stop.evaluated(6);
+        update(&mut stop, 0);
+        assert!(!stop.should_stop());
+        assert_eq!(stop.progress(), 0.6);
+
+        stop.evaluated(4);
+        assert!(stop.should_stop());
+        assert_eq!(stop.evaluations(), 10);
+    }
+
+    #[test]
+    #[should_panic]
+    fn solvers_must_report_evaluations() {
+        let mut stop = EvaluationCriterion::<()>::new(10);
+
+        update(&mut stop, 0);
+    }
+}
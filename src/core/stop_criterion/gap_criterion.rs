@@ -0,0 +1,211 @@
+use std::marker::PhantomData;
+
+use crate::{
+    analysis::batch::Gap,
+    core::{stop_criterion::Context, Objective, Problem, StopCriterion},
+};
+
+/// Stops as soon as the best solution is within a given gap of a known bound.
+///
+/// The bound is a lower bound for minimization problems and an upper bound for maximization ones, or
+/// just the best known value of the instance, e.g. from the literature, following the problem's
+/// [objective][Problem::objective]. The gap is the one of [Gap], taken relative to the bound's magnitude so
+/// negative bounds work too. It's zero when the bound is reached or surpassed.
+///
+/// Its progress is how much the gap has closed, from the first one observed to the target.
+///
+/// ```
+/// # use optimum::core::{stop_criterion::GapCriterion, Evaluation, Objective, Problem};
+/// # struct P;
+/// # impl Problem for P {
+/// #     const OBJECTIVE: Objective = Objective::Min;
+/// #     type Solution = ();
+/// #     type Value = u32;
+/// #     fn objective_function(&self, _: ()) -> Evaluation<Self> { Evaluation::new((), 0) }
+/// # }
+/// // Stops when the best solution is at most 1% above the optimal value, 7542.
+/// let stop_criterion = GapCriterion::new(&P, 7542, 1.0);
+/// ```
+#[derive(Debug)]
+pub struct GapCriterion<P> {
+    bound: f64,
+    max_gap: f64,
+    initial_gap: Option<f64>,
+    gap: Option<f64>,
+    current_iter: usize,
+    objective: Objective,
+    _p: PhantomData<P>,
+}
+
+impl<P> Clone for GapCriterion<P> {
+    fn clone(&self) -> Self {
+        Self {
+            bound: self.bound,
+            max_gap: self.max_gap,
+            initial_gap: self.initial_gap,
+            gap: self.gap,
+            current_iter: self.current_iter,
+            objective: self.objective,
+            _p: self._p,
+        }
+    }
+}
+
+impl<P: Problem> GapCriterion<P>
+where
+    P::Value: Into<f64>,
+{
+    /// Creates a gap criterion which stops once the gap to `bound` is at most `max_gap` percent, taking `bound`
+    /// in the direction of the `problem`'s objective.
+    ///
+    /// # Panics
+    ///
+    /// If `bound` is zero, since the gap is relative to it, or `max_gap` is negative.
+    pub fn new(problem: &P, bound: P::Value, max_gap: f64) -> Self {
+        let bound = bound.into();
+        assert!(bound != 0.0, "the gap to a zero bound isn't defined");
+        assert!(max_gap >= 0.0, "the gap must not be negative");
+
+        Self {
+            bound,
+            max_gap,
+            initial_gap: None,
+            gap: None,
+            current_iter: 0,
+            objective: problem.objective(),
+            _p: PhantomData,
+        }
+    }
+
+    /// The gap of the best solution seen so far, as a percentage.
+    pub fn gap(&self) -> Option<f64> {
+        self.gap
+    }
+}
+
+impl<P: Problem> StopCriterion<P> for GapCriterion<P>
+where
+    P::Value: Into<f64>,
+{
+    fn progress(&self) -> f64 {
+        match (self.initial_gap, self.gap) {
+            (_, Some(gap)) if gap <= self.max_gap => 1.0,
+            (Some(initial), Some(gap)) => {
+                ((initial - gap) / (initial - self.max_gap)).clamp(0.0, 1.0)
+            }
+            _ => 0.0,
+        }
+    }
+
+    fn update(&mut self, context: Context<'_, P>) {
+        // Relative to the magnitude of the bound, so the sign of the gap tells the direction.
+        let gap = context.best.into().gap(self.bound) * self.bound.signum();
+        let gap = match self.objective {
+            Objective::Min => gap,
+            Objective::Max => -gap,
+        }
+        .max(0.0);
+
+        self.initial_gap.get_or_insert(gap);
+        self.gap = Some(gap);
+        self.current_iter += 1;
+    }
+
+    fn current_iter(&self) -> usize {
+        self.current_iter
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::core::Evaluation;
+
+    use super::*;
+
+    /// Takes its objective at runtime.
+    struct Tour(Objective);
+
+    impl Problem for Tour {
+        const OBJECTIVE: Objective = Objective::Min;
+
+        type Solution = ();
+
+        type Value = i32;
+
+        fn objective(&self) -> Objective {
+            self.0
+        }
+
+        fn objective_function(&self, _: Self::Solution) -> Evaluation<Self> {
+            Evaluation::new((), 0)
+        }
+    }
+
+    fn update(criterion: &mut GapCriterion<Tour>, value: i32) {
+        let evaluation = Evaluation::new((), value);
+
+        criterion.update(Context {
+            candidate: &evaluation,
+            best: value,
+            improved: true,
+            elapsed: Duration::ZERO,
+            iteration: criterion.current_iter + 1,
+        });
+    }
+
+    #[test]
+    fn it_works() {
+        let mut criterion = GapCriterion::new(&Tour(Objective::Min), 100, 5.0);
+
+        assert_eq!(criterion.progress(), 0.0);
+
+        update(&mut criterion, 125);
+        assert_eq!(criterion.gap(), Some(25.0));
+        assert_eq!(criterion.progress(), 0.0);
+
+        update(&mut criterion, 115);
+        assert_eq!(criterion.progress(), 0.5);
+
+        update(&mut criterion, 105);
+        assert!(criterion.should_stop());
+    }
+
+    #[test]
+    fn upper_bounds_are_supported() {
+        let mut criterion = GapCriterion::new(&Tour(Objective::Max), 200, 0.0);
+
+        update(&mut criterion, 150);
+        assert_eq!(criterion.gap(), Some(25.0));
+        assert!(!criterion.should_stop());
+
+        // Surpassing a best known value closes the gap.
+        update(&mut criterion, 210);
+        assert_eq!(criterion.gap(), Some(0.0));
+        assert!(criterion.should_stop());
+    }
+
+    #[test]
+    fn negative_bounds_are_supported() {
+        let mut min = GapCriterion::new(&Tour(Objective::Min), -100, 5.0);
+
+        // Still 10% worse than the lower bound.
+        update(&mut min, -90);
+        assert_eq!(min.gap(), Some(10.0));
+        assert!(!min.should_stop());
+
+        update(&mut min, -96);
+        assert!(min.should_stop());
+
+        let mut max = GapCriterion::new(&Tour(Objective::Max), -100, 5.0);
+
+        update(&mut max, -120);
+        assert_eq!(max.gap(), Some(20.0));
+        assert!(!max.should_stop());
+
+        update(&mut max, -90);
+        assert_eq!(max.gap(), Some(0.0));
+        assert!(max.should_stop());
+    }
+}
@@ -1,44 +1,51 @@
+use std::marker::PhantomData;
+
 use num_traits::{One, Zero};
 
-use crate::core::{Objective, Problem};
+use crate::core::Problem;
 
-use super::StopCriterion;
+use super::{Context, StopCriterion};
 
 /// The stop criterion is met when the solver executes N iterations in sequence without improving the best solution.
-#[derive(Debug, Clone)]
-pub struct ImprovementCriterion<P: Problem> {
+///
+/// Improvements are told by the solver through [Context::improved], so they follow its [objective][crate::core::Solver::objective].
+#[derive(Debug)]
+pub struct ImprovementCriterion<P> {
     last_improvement: usize,
-    best: P::Value,
     max_without_improvement: usize,
     current_iter: usize,
+    _p: PhantomData<P>,
+}
+
+impl<P> Clone for ImprovementCriterion<P> {
+    fn clone(&self) -> Self {
+        Self {
+            last_improvement: self.last_improvement,
+            max_without_improvement: self.max_without_improvement,
+            current_iter: self.current_iter,
+            _p: self._p,
+        }
+    }
 }
 
 impl<P: Problem> ImprovementCriterion<P> {
     /// Creates a new improvement criterion.
     ///
-    /// - `initial` is the initial best value (a very high value for minimization or very low value for maximization).
     /// - `max_iters` number of iterations in sequence without improvement to stop.
-    pub fn new(initial: P::Value, max_iters: usize) -> Self {
+    pub fn new(max_iters: usize) -> Self {
         Self {
-            best: initial,
             last_improvement: 0,
             current_iter: 0,
             max_without_improvement: max_iters,
+            _p: PhantomData,
         }
     }
 
     fn improvement_took_too_long(&self) -> bool {
-        let iterations_without_improvement = self.current_iter() - self.last_improvement;
+        let iterations_without_improvement = self.current_iter - self.last_improvement;
 
         iterations_without_improvement > self.max_without_improvement
     }
-
-    fn best_solution_improved(&self, other: P::Value) -> bool {
-        match P::OBJECTIVE {
-            Objective::Min => other < self.best,
-            Objective::Max => other > self.best,
-        }
-    }
 }
 
 impl<P: Problem> StopCriterion<P> for ImprovementCriterion<P> {
@@ -50,12 +57,11 @@ impl<P: Problem> StopCriterion<P> for ImprovementCriterion<P> {
         }
     }
 
-    fn update(&mut self, new_value: <P as Problem>::Value) {
+    fn update(&mut self, context: Context<'_, P>) {
         self.current_iter += 1;
 
-        if self.best_solution_improved(new_value) {
-            self.best = new_value;
-            self.last_improvement = self.current_iter();
+        if context.improved {
+            self.last_improvement = self.current_iter;
         }
     }
 
@@ -66,21 +72,47 @@ impl<P: Problem> StopCriterion<P> for ImprovementCriterion<P> {
 
 #[cfg(test)]
 mod tests {
+    use std::time::Duration;
+
+    use crate::core::Evaluation;
+
     use super::*;
 
-    // TODO add better test cases
+    fn update(criterion: &mut ImprovementCriterion<()>, improved: bool) {
+        let evaluation = Evaluation::new((), 0);
+
+        criterion.update(Context {
+            candidate: &evaluation,
+            best: 0,
+            improved,
+            elapsed: Duration::ZERO,
+            iteration: criterion.current_iter + 1,
+        });
+    }
 
     #[test]
     fn it_works() {
         let max = 10;
-        let mut criterion = ImprovementCriterion::<()>::new(0, max);
+        let mut criterion = ImprovementCriterion::<()>::new(max);
 
-        (0..max).for_each(|_| criterion.update(0));
+        (0..max).for_each(|_| update(&mut criterion, false));
 
         assert_ne!(criterion.progress(), f64::one());
 
-        criterion.update(0);
+        update(&mut criterion, false);
 
         assert_eq!(criterion.progress(), f64::one());
     }
+
+    #[test]
+    fn improvements_reset_the_count() {
+        let mut criterion = ImprovementCriterion::<()>::new(1);
+
+        for _ in 0..5 {
+            update(&mut criterion, false);
+            update(&mut criterion, true);
+        }
+
+        assert_ne!(criterion.progress(), f64::one());
+    }
 }
@@ -2,7 +2,7 @@ use std::marker::PhantomData;
 
 use crate::core::Problem;
 
-use super::StopCriterion;
+use super::{Context, StopCriterion};
 
 /// The execution stops after N iterations.
 #[derive(Debug)]
@@ -40,7 +40,7 @@ impl<P: Problem> StopCriterion<P> for IterCriterion<P> {
         self.current_iter as f64 / self.max_iter as f64
     }
 
-    fn update(&mut self, _: P::Value) {
+    fn update(&mut self, _: Context<'_, P>) {
         self.current_iter += 1;
     }
 
@@ -52,13 +52,14 @@ impl<P: Problem> StopCriterion<P> for IterCriterion<P> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::stop_criterion::test_helpers::update;
 
     #[test]
     fn it_works() {
         let mut stop = IterCriterion::<()>::new(10);
         for _ in 0..10 {
             assert!(!stop.should_stop());
-            stop.update(0);
+            update(&mut stop, 0);
         }
         assert!(stop.should_stop());
         assert_eq!(stop.current_iter(), 10);
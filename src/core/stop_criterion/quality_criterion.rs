@@ -1,4 +1,4 @@
-use crate::core::{Objective, Problem, StopCriterion};
+use crate::core::{stop_criterion::Context, Comparison, Objective, Problem, StopCriterion};
 
 use num_traits::{One, Zero};
 
@@ -8,6 +8,7 @@ pub struct QualityCriterion<P: Problem> {
     target: P::Value,
     done: bool,
     current_iter: usize,
+    objective: Objective,
 }
 
 impl<P: Problem> QualityCriterion<P> {
@@ -17,8 +18,15 @@ impl<P: Problem> QualityCriterion<P> {
             target,
             done: false,
             current_iter: 0,
+            objective: P::OBJECTIVE,
         }
     }
+
+    /// Compares values with `target` in the `objective` direction instead of [Problem::OBJECTIVE].
+    pub fn with_objective(mut self, objective: Objective) -> Self {
+        self.objective = objective;
+        self
+    }
 }
 
 impl<P: Problem> StopCriterion<P> for QualityCriterion<P> {
@@ -30,11 +38,8 @@ impl<P: Problem> StopCriterion<P> for QualityCriterion<P> {
         }
     }
 
-    fn update(&mut self, new_value: <P as Problem>::Value) {
-        self.done = match P::OBJECTIVE {
-            Objective::Min => new_value <= self.target,
-            Objective::Max => new_value >= self.target,
-        };
+    fn update(&mut self, context: Context<'_, P>) {
+        self.done = self.objective.compare(context.best, self.target) != Comparison::Worse;
         self.current_iter += 1;
     }
 
@@ -46,6 +51,7 @@ impl<P: Problem> StopCriterion<P> for QualityCriterion<P> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::stop_criterion::test_helpers::update;
 
     #[test]
     fn it_works() {
@@ -53,8 +59,19 @@ mod tests {
 
         assert_ne!(criterion.progress(), f64::one());
 
-        criterion.update(0);
+        update(&mut criterion, 0);
+
+        assert_eq!(criterion.progress(), f64::one());
+    }
+
+    #[test]
+    fn objective_may_be_changed() {
+        let mut criterion = QualityCriterion::<()>::new(5).with_objective(Objective::Max);
+
+        update(&mut criterion, 3);
+        assert_ne!(criterion.progress(), f64::one());
 
+        update(&mut criterion, 6);
         assert_eq!(criterion.progress(), f64::one());
     }
 }
@@ -0,0 +1,85 @@
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use crate::core::{stop_criterion::Context, Problem, StopCriterion};
+
+/// Shares a criterion among solvers running in separate threads.
+///
+/// Clones refer to the same criterion, so the iterations of every solver are counted together and
+/// all of them stop at once. It's used by [Portfolio][crate::core::solver::Portfolio].
+#[derive(Debug)]
+pub struct SharedCriterion<SC> {
+    inner: Arc<Mutex<SC>>,
+}
+
+impl<SC> Clone for SharedCriterion<SC> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: Arc::clone(&self.inner),
+        }
+    }
+}
+
+impl<SC> SharedCriterion<SC> {
+    /// Wraps `stop_criterion` to share it.
+    pub fn new(stop_criterion: SC) -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(stop_criterion)),
+        }
+    }
+
+    /// Gives access to the criterion being shared.
+    pub fn lock(&self) -> MutexGuard<'_, SC> {
+        self.inner.lock().unwrap()
+    }
+}
+
+impl<P: Problem, SC: StopCriterion<P>> StopCriterion<P> for SharedCriterion<SC> {
+    fn progress(&self) -> f64 {
+        self.lock().progress()
+    }
+
+    fn should_stop(&self) -> bool {
+        self.lock().should_stop()
+    }
+
+    fn started(&mut self) {
+        self.lock().started();
+    }
+
+    fn update(&mut self, context: Context<'_, P>) {
+        self.lock().update(context);
+    }
+
+    fn evaluated(&mut self, count: usize) {
+        self.lock().evaluated(count);
+    }
+
+    fn current_iter(&self) -> usize {
+        self.lock().current_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use crate::core::stop_criterion::IterCriterion;
+
+    use super::*;
+    use crate::core::stop_criterion::test_helpers::update;
+
+    #[test]
+    fn iterations_are_counted_together() {
+        let stop_criterion = SharedCriterion::new(IterCriterion::<()>::new(10));
+
+        thread::scope(|s| {
+            for _ in 0..2 {
+                let mut stop_criterion = stop_criterion.clone();
+                s.spawn(move || (0..5).for_each(|i| update(&mut stop_criterion, i)));
+            }
+        });
+
+        assert_eq!(stop_criterion.current_iter(), 10);
+        assert!(stop_criterion.should_stop());
+    }
+}
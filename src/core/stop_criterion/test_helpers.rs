@@ -1,5 +1,9 @@
+use std::time::Duration;
+
 use crate::core::{Evaluation, Objective, Problem};
 
+use super::{Context, StopCriterion};
+
 impl Problem for () {
     const OBJECTIVE: Objective = Objective::Min;
 
@@ -11,3 +15,16 @@ impl Problem for () {
         Evaluation::new((), 0)
     }
 }
+
+/// Updates `criterion` as if `value` was generated in its next iteration and became the best.
+pub(crate) fn update<SC: StopCriterion<()>>(criterion: &mut SC, value: usize) {
+    let evaluation = Evaluation::new((), value);
+
+    criterion.update(Context {
+        candidate: &evaluation,
+        best: value,
+        improved: true,
+        elapsed: Duration::ZERO,
+        iteration: criterion.current_iter() + 1,
+    });
+}
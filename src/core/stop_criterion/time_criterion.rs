@@ -1,66 +1,183 @@
-use std::{
-    marker::PhantomData,
-    time::{Duration, Instant},
-};
+use std::{marker::PhantomData, sync::OnceLock, time::Duration};
 
-use crate::core::{Problem, StopCriterion};
+use crate::core::{stop_criterion::Context, Problem, StopCriterion};
+
+use super::{Clock, SystemClock};
 
 /// This criterion is based on a maximum duration.
 ///
-/// The timer starts as soon as the criterion is created (i.e. through [new][TimeCriterion::new])
-/// and stops when it exceeds the duration given.
-pub struct TimeCriterion<P> {
+/// The timer is idle until [started][TimeCriterion::start], which [Solver::solve][crate::core::Solver::solve]
+/// does before the first iteration, and stops when it exceeds the duration given. If it's never started,
+/// it starts on its own the first time it's [updated][StopCriterion::update] or asked whether it
+/// [should stop][StopCriterion::should_stop], so code which doesn't call [started][StopCriterion::started]
+/// still stops. It may also be [paused][TimeCriterion::pause] and [reset][TimeCriterion::reset], e.g. so the
+/// time spent elsewhere isn't counted.
+///
+/// Clones keep the state of the timer, so a criterion which wasn't started yet may be cloned for each
+/// execution of a [Batch][crate::analysis::batch::Batch], giving each of them the whole duration.
+///
+/// Time is measured by a [Clock], which is the [SystemClock] by default. A [ManualClock][super::ManualClock]
+/// makes the criterion deterministic:
+///
+/// ```
+/// # use std::time::Duration;
+/// # use optimum::core::{stop_criterion::{ManualClock, TimeCriterion}, Evaluation, Objective, Problem, StopCriterion};
+/// # struct P;
+/// # impl Problem for P {
+/// #     const OBJECTIVE: Objective = Objective::Min;
+/// #     type Solution = ();
+/// #     type Value = usize;
+/// #     fn objective_function(&self, _: ()) -> Evaluation<Self> { Evaluation::new((), 0) }
+/// # }
+/// let clock = ManualClock::new();
+/// let mut stop_criterion = TimeCriterion::<P, _>::with_clock(Duration::from_secs(10), clock.clone());
+///
+/// stop_criterion.start();
+/// clock.advance(Duration::from_secs(10));
+///
+/// assert!(stop_criterion.should_stop());
+/// ```
+pub struct TimeCriterion<P, C = SystemClock> {
     current_iter: usize,
-    start: Instant,
-    elapsed: Duration,
+    clock: C,
+    /// When the timer was last started, if it's running.
+    started_at: Option<Duration>,
+    /// Time counted before the timer was last started.
+    accumulated: Duration,
+    /// When the timer started on its own, as it was checked before being started.
+    implicit_start: OnceLock<Duration>,
+    /// Whether the timer was started or paused explicitly since it was created or reset.
+    controlled: bool,
     duration: Duration,
     _p: PhantomData<P>,
 }
 
-impl<P> std::fmt::Debug for TimeCriterion<P> {
+impl<P, C: std::fmt::Debug> std::fmt::Debug for TimeCriterion<P, C> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("TimeCriterion")
             .field("current_iter", &self.current_iter)
-            .field("start", &self.start)
-            .field("elapsed", &self.elapsed)
+            .field("clock", &self.clock)
+            .field("started_at", &self.started_at)
+            .field("accumulated", &self.accumulated)
+            .field("implicit_start", &self.implicit_start)
+            .field("controlled", &self.controlled)
             .field("duration", &self.duration)
             .field("_p", &self._p)
             .finish()
     }
 }
 
-impl<P> Clone for TimeCriterion<P> {
+impl<P, C: Clone> Clone for TimeCriterion<P, C> {
     fn clone(&self) -> Self {
-        Self::new(self.duration)
+        Self {
+            current_iter: self.current_iter,
+            clock: self.clock.clone(),
+            started_at: self.started_at,
+            accumulated: self.accumulated,
+            implicit_start: self.implicit_start.clone(),
+            controlled: self.controlled,
+            duration: self.duration,
+            _p: self._p,
+        }
     }
 }
 
 impl<P> TimeCriterion<P> {
-    #[allow(dead_code)]
-    /// Creates a new time criterion.
+    /// Creates a new time criterion, measured by the [SystemClock].
+    ///
+    /// The timer is idle until [started][Self::start], which [Solver::solve][crate::core::Solver::solve] does,
+    /// or until it's first checked or updated.
     pub fn new(duration: Duration) -> Self {
-        assert!(!duration.is_zero());
+        Self::with_clock(duration, SystemClock::default())
+    }
+}
 
-        let start = Instant::now();
+impl<P, C: Clock> TimeCriterion<P, C> {
+    /// Creates a new time criterion, measured by `clock`.
+    pub fn with_clock(duration: Duration, clock: C) -> Self {
+        assert!(!duration.is_zero());
 
         Self {
             current_iter: 0,
-            start,
-            elapsed: start.elapsed(),
+            clock,
+            started_at: None,
+            accumulated: Duration::ZERO,
+            implicit_start: OnceLock::new(),
+            controlled: false,
             duration,
             _p: PhantomData,
         }
     }
+
+    /// Starts the timer, or resumes it if it was paused. Does nothing if it's already running.
+    pub fn start(&mut self) {
+        if self.started_at.is_none() {
+            let started_at = self.running_since().unwrap_or_else(|| self.clock.now());
+            self.started_at = Some(started_at);
+        }
+        self.implicit_start.take();
+        self.controlled = true;
+    }
+
+    /// Stops counting time until the timer is [started][Self::start] again.
+    pub fn pause(&mut self) {
+        self.accumulated = self.elapsed();
+        self.started_at = None;
+        self.implicit_start.take();
+        self.controlled = true;
+    }
+
+    /// Brings the criterion back to its initial state: idle, with no time or iterations counted.
+    pub fn reset(&mut self) {
+        self.accumulated = Duration::ZERO;
+        self.started_at = None;
+        self.implicit_start.take();
+        self.controlled = false;
+        self.current_iter = 0;
+    }
+
+    /// Tells if the timer is counting time.
+    pub fn is_running(&self) -> bool {
+        self.running_since().is_some()
+    }
+
+    /// The time counted so far.
+    pub fn elapsed(&self) -> Duration {
+        let running = self
+            .running_since()
+            .map_or(Duration::ZERO, |started_at| self.clock.now() - started_at);
+
+        self.accumulated + running
+    }
+
+    /// When the timer was last started, explicitly or on its own, if it's running.
+    fn running_since(&self) -> Option<Duration> {
+        self.started_at
+            .or_else(|| self.implicit_start.get().copied())
+    }
+
+    /// Starts the timer on its own if it was never started or paused explicitly.
+    fn start_if_untouched(&self) {
+        if !self.controlled {
+            self.implicit_start.get_or_init(|| self.clock.now());
+        }
+    }
 }
 
-impl<P: Problem> StopCriterion<P> for TimeCriterion<P> {
+impl<P: Problem, C: Clock> StopCriterion<P> for TimeCriterion<P, C> {
     fn progress(&self) -> f64 {
+        self.start_if_untouched();
+
         // `duration` is always different from zero, so `ratio` is a finite value.
-        self.elapsed.as_secs_f64() / self.duration.as_secs_f64()
+        self.elapsed().as_secs_f64() / self.duration.as_secs_f64()
     }
 
-    fn update(&mut self, _: <P as Problem>::Value) {
-        self.elapsed = self.start.elapsed();
+    fn started(&mut self) {
+        self.start();
+    }
+
+    fn update(&mut self, _: Context<'_, P>) {
+        self.start_if_untouched();
         self.current_iter += 1;
     }
 
@@ -72,26 +189,105 @@ impl<P: Problem> StopCriterion<P> for TimeCriterion<P> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::stop_criterion::{test_helpers::update, ManualClock};
 
     use num_traits::Zero;
 
+    fn setup() -> (TimeCriterion<(), ManualClock>, ManualClock) {
+        let clock = ManualClock::new();
+        let stop = TimeCriterion::with_clock(Duration::from_secs(10), clock.clone());
+
+        (stop, clock)
+    }
+
     #[test]
     fn it_works() {
-        let mut stop = TimeCriterion::<()>::new(Duration::from_secs(10));
+        let (mut stop, clock) = setup();
+        stop.start();
         assert_eq!(stop.current_iter(), 0);
         assert!((stop.progress() - f64::zero()).abs() <= 1e-6);
 
         assert!(!stop.should_stop());
 
-        stop.update(0);
+        update(&mut stop, 0);
 
         assert_eq!(stop.current_iter(), 1);
 
-        // Manually force time passing
-        stop.elapsed += Duration::from_secs(10);
+        clock.advance(Duration::from_secs(5));
+        assert_eq!(stop.progress(), 0.5);
 
+        clock.advance(Duration::from_secs(5));
         assert!(stop.should_stop());
     }
 
-    // TODO add tests for Clone because the semantic is customized
+    #[test]
+    fn time_is_only_counted_while_running() {
+        let (mut stop, clock) = setup();
+
+        clock.advance(Duration::from_secs(3));
+        assert_eq!(stop.elapsed(), Duration::ZERO);
+
+        stop.start();
+        clock.advance(Duration::from_secs(3));
+        stop.pause();
+        clock.advance(Duration::from_secs(3));
+        assert_eq!(stop.elapsed(), Duration::from_secs(3));
+
+        stop.start();
+        clock.advance(Duration::from_secs(1));
+        assert_eq!(stop.elapsed(), Duration::from_secs(4));
+
+        stop.reset();
+        assert!(!stop.is_running());
+        assert_eq!(stop.elapsed(), Duration::ZERO);
+    }
+
+    #[test]
+    fn it_starts_on_its_own() {
+        let (checked, clock) = setup();
+        let (mut updated, other_clock) = setup();
+
+        assert!(!checked.should_stop());
+        update(&mut updated, 0);
+        clock.advance(Duration::from_secs(10));
+        other_clock.advance(Duration::from_secs(4));
+
+        assert!(checked.should_stop());
+        assert!(updated.is_running());
+        assert_eq!(updated.elapsed(), Duration::from_secs(4));
+
+        // Starting it explicitly afterwards keeps the time already counted.
+        updated.start();
+        assert_eq!(updated.elapsed(), Duration::from_secs(4));
+    }
+
+    #[test]
+    fn paused_timers_dont_start_on_their_own() {
+        let (mut stop, clock) = setup();
+
+        stop.start();
+        clock.advance(Duration::from_secs(2));
+        stop.pause();
+        clock.advance(Duration::from_secs(20));
+
+        assert!(!stop.should_stop());
+        assert!(!stop.is_running());
+        assert_eq!(stop.elapsed(), Duration::from_secs(2));
+    }
+
+    #[test]
+    fn clones_keep_the_timer() {
+        let (mut stop, clock) = setup();
+
+        let idle = stop.clone();
+        stop.start();
+        clock.advance(Duration::from_secs(2));
+        let running = stop.clone();
+
+        assert_eq!(idle.elapsed(), Duration::ZERO);
+        assert_eq!(running.elapsed(), Duration::from_secs(2));
+
+        clock.advance(Duration::from_secs(2));
+        assert_eq!(running.elapsed(), stop.elapsed());
+    }
 }
@@ -1,6 +1,7 @@
 //! This module keeps implementations for metaheuristics defined in papers and used across industry.
 //!
-//! Currently, there's an implementation of the [BRKGA][genetic::brkga::Brkga] metaheuristic ready to use.
+//! Currently, there are implementations of the [BRKGA][genetic::brkga::Brkga] metaheuristic and of a classic
+//! [genetic algorithm][genetic::ga::Ga] ready to use.
 //!
 //! [neighborhood]-based metaheuristics are WIP, but there's some stuff there.
 
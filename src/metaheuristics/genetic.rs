@@ -2,11 +2,15 @@
 //!
 //! This module contains genetic-based metaheuristics.
 //!
-//! Currently there's an implementation of [BRKGA][brkga], but more may be added in the future.
+//! Currently there are implementations of [BRKGA][brkga], of a classic [genetic algorithm][ga] with pluggable operators
+//! and of [NSGA-II][nsga2] for multi-objective problems, but more may be added in the future.
 //!
 
 pub mod brkga;
+pub mod ga;
+pub mod nsga2;
 
+pub mod parallel;
 pub mod population;
 
 mod decoder;
@@ -2,55 +2,94 @@
 //!
 //! This implementation of the Biased Random-Key Genetic Algorithm is a Rust port of the [brkgaAPI](http://mauricio.resende.info/src/brkgaAPI/).
 //!
-//! The features of _multiple populations_ and _optional parallel decoding_ are not present, but may be added later.
+//! Multiple independent populations (islands) are supported through [Params::populations()]. Every
+//! [Params::exchange_interval()] generations, the best [Params::exchange_count()] members of each population
+//! are copied into the others, just like brkgaAPI's `exchangeElite`.
+//!
+//! When the search stagnates, the populations may be restarted. See [RestartStrategy].
+//!
+//! Besides the crossover between one elite and one non elite parent, there's the multi-parent crossover
+//! of BRKGA-MP. See [MultiParent].
+//!
+//! Path relinking between elites, as in BRKGA-MP-IPR, may be performed periodically. See [PathRelinking].
+//!
+//! The initial populations may be seeded with known chromosomes through a [SeededMemberBuilder],
+//! and chromosomes may be [injected][Brkga::inject] into an evolving population at any time.
+//!
+//! Decoders may improve the members they decode and write the improvements back into their keys.
+//! See [decode_value_mut][Decoder::decode_value_mut].
+//!
+//! With the `serde` feature, a run may be saved through a `Snapshot` and resumed later. See `Brkga::snapshot`.
+//!
+//! The members may be decoded in parallel by wrapping the decoder in a `Parallel` one, with the `parallel` feature. See [parallel].
+//!
 //! The rest should behave the same.
 //!
 //! The main items here are [Brkga] and its [Params].
 //!
 
 use crate::core::{
-    solver::IterHook,
-    Problem, StopCriterion, {Evaluation, Solver},
+    solver::{hook::Record, AcceptsIncumbent, IterHook},
+    Comparison, Objective, Problem, StopCriterion, {Evaluation, Solver},
 };
 
 use super::{
+    parallel,
     population::{Member, Population},
     Decoder, RandomKey,
 };
 
-use std::{num::NonZeroUsize, usize};
-
-use rand::{prelude::SliceRandom, Rng};
+use rand::{prelude::SliceRandom, seq::index, Rng};
 
 mod member_builder;
-use member_builder::MemberBuilder;
-pub use member_builder::RandomMemberBuilder;
+pub use member_builder::{Ctx, MemberBuilder, RandomMemberBuilder, SeededMemberBuilder};
 
-/// The interface to execute the BRKGA algorithm.
-///
+mod params;
+pub use params::{Params, ParamsBuilder, ParamsError, RestartStrategy};
+
+mod multi_parent;
+pub use multi_parent::{BiasFunction, MultiParent};
+
+mod path_relinking;
+pub use path_relinking::{PathRelinking, RelinkingKind};
+
+#[cfg(test)]
+mod test_helpers;
+
+#[cfg(feature = "serde")]
+mod snapshot;
+#[cfg(feature = "serde")]
+pub use snapshot::{Snapshot, SnapshotError};
 
 // TODO use a real doctest
 
+/// The interface to execute the BRKGA algorithm.
+///
 /// ```ignore
 /// let brkga = Brkga::new(decoder, rng, params, RandomMemberBuilder);
 ///
-/// println!("Initial value: {}", brkga.best().value);
+/// println!("Initial value: {}", brkga.best().value());
 ///
 /// // Apply evolutions
 /// for _ in 0..100 {
 ///     brkga.evolve();
 /// }
 ///
-/// println!("Final value: {}", brkga.best().value);
+/// println!("Final value: {}", brkga.best().value());
 /// ```
 ///
-pub struct Brkga<'a, D: Decoder, R: Rng> {
+pub struct Brkga<'a, D: Decoder, R: Rng, B = RandomMemberBuilder> {
     decoder: &'a D,
     rng: R,
-    current: BrkgaPopulation<D>,
-    next: BrkgaPopulation<D>,
+    member_builder: B,
+    current: Vec<BrkgaPopulation<D>>,
+    next: Vec<BrkgaPopulation<D>>,
     generations: usize,
     params: Params,
+    best_value: <D::P as Problem>::Value,
+    last_improvement: usize,
+    restarts: usize,
+    evaluations: usize,
 }
 
 /// The population type used by BRKGA
@@ -59,57 +98,296 @@ pub type BrkgaPopulation<D> = Population<<D as Decoder>::P, RandomKey>;
 /// The member type used by BRKGA
 pub type BrkgaMember<D> = Member<RandomKey, <<D as Decoder>::P as Problem>::Value>;
 
-impl<'a, R: Rng, D: Decoder> Brkga<'a, D, R> {
+impl<'a, R: Rng, D: Decoder, B: MemberBuilder<D, R>> Brkga<'a, D, R, B> {
     /// Creates a new BRKGA instance, which solves the [Problem][crate::core::Problem] defined by the [Decoder].
-    pub fn new(
-        decoder: &'a D,
-        mut rng: R,
-        params: Params,
-        mut member_builder: impl MemberBuilder<D, R>,
-    ) -> Self {
-        let mut ctx = member_builder::Ctx {
+    ///
+    /// Each one of the [populations][Params::populations] is generated independently through `member_builder`,
+    /// which is kept to [reinitialize][RestartStrategy::Reinitialize] them later.
+    pub fn new(decoder: &'a D, mut rng: R, params: Params, mut member_builder: B) -> Self {
+        let mut ctx = Ctx {
             decoder,
             rng: &mut rng,
-            member_size: params.member_size.get(),
+            member_size: params.member_size(),
         };
-        let builder = |number| member_builder.build(&mut ctx, number);
 
-        let current = Population::new(params.population_size.get(), builder);
+        let current = (0..params.populations())
+            .map(|_| {
+                let size = params.population_size();
+                let members = member_builder.build_population(&mut ctx, size);
+                let mut population = Population::from_members(members);
+                population.sort_for(decoder.problem().objective());
+                population
+            })
+            .collect::<Vec<_>>();
         let next = current.clone();
+        let best_value = current[0][0].value();
 
-        Self {
+        let mut brkga = Self {
             current,
             decoder,
             params,
             next,
             generations: 0,
             rng,
-        }
+            member_builder,
+            best_value,
+            last_improvement: 0,
+            restarts: 0,
+            evaluations: params.populations() * params.population_size(),
+        };
+        brkga.best_value = brkga.best().value();
+        brkga
     }
 
-    /// Performs the evolution of the population defined by the algorithm.
+    /// Performs the evolution of every population defined by the algorithm.
     ///
     /// 1. Elites are transferred to the next generation.
     /// 2. Crossover is performed between elites and non elites to generate new members.
     /// 3. The worse members are exchanged by mutants.
+    /// 4. When it's time to, the best members of each population are exchanged between them.
+    /// 5. When it's time to, [path relinking][Self::relink] is performed in each population.
+    /// 6. If the best member wasn't improved for too long, the populations are [restarted][Self::restart].
     ///
     pub fn evolve(&mut self) {
-        self.transfer_elites();
-        self.crossover();
+        for population in 0..self.current.len() {
+            self.transfer_elites(population);
+            self.crossover(population);
+        }
         std::mem::swap(&mut self.current, &mut self.next);
-        self.mutate_current();
-        self.recompute_current();
+        for population in 0..self.current.len() {
+            self.mutate_current(population);
+            self.recompute_current(population);
+        }
 
         self.generations += 1;
+
+        if self.should_exchange() {
+            self.exchange_elite(self.params.exchange_count());
+        }
+
+        if self.should_relink() {
+            self.relink();
+        }
+
+        if self.stagnated() {
+            self.restart();
+        }
+    }
+
+    /// Performs the [PathRelinking] defined in the [Params] in every population.
+    ///
+    /// The first pair of elites, in order of quality, which are far enough from each other is relinked,
+    /// walking from the better one toward the other. It's done automatically every
+    /// [path_relinking_interval][Params::path_relinking_interval] generations, but it may be called at any moment.
+    pub fn relink(&mut self) {
+        for population in 0..self.current.len() {
+            self.relink_population(population);
+        }
+    }
+
+    fn should_relink(&self) -> bool {
+        let interval = self.params.path_relinking_interval();
+        interval > 0 && self.generations.is_multiple_of(interval)
+    }
+
+    fn relink_population(&mut self, population: usize) {
+        let config = self.params.path_relinking();
+        let decoder = self.decoder;
+        let objective = self.objective();
+        let better = |a, b| objective.compare(a, b) == Comparison::Better;
+
+        let current = &self.current[population];
+        let elites = Self::elites(current, &self.params);
+
+        let pair = (0..elites.len())
+            .flat_map(|a| ((a + 1)..elites.len()).map(move |b| (a, b)))
+            .map(|(a, b)| (elites[a].keys(), elites[b].keys()))
+            .find(|(a, b)| config.kind.distance(a, b) >= config.min_distance);
+
+        let Some((base, guide)) = pair else {
+            return;
+        };
+
+        let mut decoded = 0;
+        let relinked = path_relinking::relink(
+            config,
+            base,
+            guide,
+            |keys| {
+                decoded += 1;
+                decoder.decode_value(keys)
+            },
+            better,
+        );
+        self.evaluations += decoded;
+
+        if let Some((keys, value)) = relinked {
+            let worst = self.current[population].members.last_mut().unwrap();
+
+            if better(value, worst.value()) {
+                // It was decoded while relinking, so its value is already known.
+                worst.keys_mut().copy_from_slice(&keys);
+                worst.set_value(value);
+                self.current[population].sort_for(objective);
+            }
+        }
+    }
+
+    /// Applies the [RestartStrategy] defined in the [Params] to every population.
+    ///
+    /// It's done automatically after [restart_after][Params::restart_after] generations without improvements,
+    /// but it may be called at any moment.
+    pub fn restart(&mut self) {
+        for population in 0..self.current.len() {
+            match self.params.restart_strategy() {
+                RestartStrategy::Reinitialize => self.reinitialize(population),
+                RestartStrategy::Shake(probability) => self.shake(population, probability),
+            }
+        }
+
+        self.last_improvement = self.generations;
+        self.restarts += 1;
+    }
+
+    /// Decodes `keys` and puts them into `population` in place of its worst member.
+    ///
+    /// It may be called at any moment, e.g. to bring in solutions found by a heuristic during the search.
+    ///
+    /// # Panics
+    ///
+    /// When `keys` doesn't have [member_size][Params::member_size] keys or `population` doesn't exist.
+    pub fn inject(&mut self, mut keys: Box<[RandomKey]>, population: usize) {
+        assert_eq!(
+            keys.len(),
+            self.params.member_size(),
+            "injected members must have one key per gene"
+        );
+
+        let value = self.decoder.decode_value_mut(&mut keys);
+        self.evaluations += 1;
+        let objective = self.objective();
+        let population = &mut self.current[population];
+        *population.members.last_mut().unwrap() = Member::new(keys, value);
+        population.sort_for(objective);
+    }
+
+    /// Number of restarts performed so far.
+    pub fn restarts(&self) -> usize {
+        self.restarts
+    }
+
+    /// Number of members decoded so far, including the initial populations.
+    pub fn evaluations(&self) -> usize {
+        self.evaluations
+    }
+
+    /// Keeps track of improvements to tell if it's time to restart.
+    fn stagnated(&mut self) -> bool {
+        let best = self.best().value();
+
+        if self.objective().compare(best, self.best_value) == Comparison::Better {
+            self.best_value = best;
+            self.last_improvement = self.generations;
+        }
+
+        let after = self.params.restart_after();
+        after > 0 && self.generations - self.last_improvement >= after
+    }
+
+    /// Replaces the non elite members by new ones from the member builder.
+    fn reinitialize(&mut self, population: usize) {
+        let mut ctx = Ctx {
+            decoder: self.decoder,
+            rng: &mut self.rng,
+            member_size: self.params.member_size(),
+        };
+        let size = self.params.population_size() - self.params.elites();
+        let members = self.member_builder.build_population(&mut ctx, size);
+        self.evaluations += size;
+        let objective = self.decoder.problem().objective();
+
+        let population = &mut self.current[population];
+        for (target, member) in population.members[self.params.elites()..]
+            .iter_mut()
+            .zip(members)
+        {
+            *target = member;
+        }
+        population.sort_for(objective);
+    }
+
+    /// Randomizes keys of the elites, except the best one.
+    fn shake(&mut self, population: usize, probability: f64) {
+        let elites = &mut self.current[population].members[1..self.params.elites()];
+
+        for elite in elites {
+            for key in elite.keys_mut() {
+                if self.rng.gen::<f64>() < probability {
+                    *key = self.rng.gen();
+                }
+            }
+        }
+
+        self.recompute_current(population);
+    }
+
+    /// Copies the `count` best members of each population into the other ones, replacing their worse members.
+    ///
+    /// It's the equivalent of brkgaAPI's `exchangeElite`. It does nothing if there's only one population.
+    ///
+    /// # Panics
+    ///
+    /// It will panic if `count * (populations - 1)` isn't smaller than the size of a population,
+    /// as the received members would replace every member of a population.
+    pub fn exchange_elite(&mut self, count: usize) {
+        let populations = self.current.len();
+        let size = self.params.population_size();
+
+        assert!(
+            count * (populations - 1) < size,
+            "too many members to exchange between populations"
+        );
+
+        // Copied before any population receives members, which may replace some of the ones being sent.
+        let emigrants = self
+            .current
+            .iter()
+            .map(|population| population.members[..count].to_vec())
+            .collect::<Vec<_>>();
+
+        for (target, population) in self.current.iter_mut().enumerate() {
+            let immigrants = emigrants
+                .iter()
+                .enumerate()
+                .filter(|&(source, _)| source != target)
+                .flat_map(|(_, members)| members);
+
+            for (replaced, immigrant) in population.members.iter_mut().rev().zip(immigrants) {
+                replaced.copy_from(immigrant);
+            }
+        }
+
+        let objective = self.objective();
+        for population in self.current.iter_mut() {
+            population.sort_for(objective);
+        }
+    }
+
+    fn should_exchange(&self) -> bool {
+        let interval = self.params.exchange_interval();
+
+        self.current.len() > 1
+            && self.params.exchange_count() > 0
+            && interval > 0
+            && self.generations.is_multiple_of(interval)
     }
 
     /// Copy the elites from `current` to `next`.
-    fn transfer_elites(&mut self) {
-        let elites = Self::elites(&self.current, &self.params);
+    fn transfer_elites(&mut self, population: usize) {
+        let elites = Self::elites(&self.current[population], &self.params);
 
-        for (elite, target) in elites.iter().zip(self.next.members.iter_mut()) {
-            target.keys.copy_from_slice(&elite.keys);
-            target.value = elite.value;
+        for (elite, target) in elites.iter().zip(self.next[population].members.iter_mut()) {
+            target.copy_from(elite);
         }
     }
 
@@ -119,17 +397,23 @@ impl<'a, R: Rng, D: Decoder> Brkga<'a, D, R> {
     /// it selects an elite parent and a non elite parent and generates a child
     /// by randomly choosing which key comes from which parent
     /// based on the crossover bias parameter.
-    fn crossover(&mut self) {
-        for member in Self::regulars(&mut self.next, &self.params) {
-            let elite_parent = Self::elites(&self.current, &self.params)
+    fn crossover(&mut self, population: usize) {
+        if let Some(multi_parent) = self.params.multi_parent() {
+            return self.multi_parent_crossover(population, multi_parent);
+        }
+
+        let current = &self.current[population];
+
+        for member in Self::regulars(&mut self.next[population], &self.params) {
+            let elite_parent = Self::elites(current, &self.params)
                 .choose(&mut self.rng)
                 .unwrap();
-            let non_elite_parent = Self::not_elites(&self.current, &self.params)
+            let non_elite_parent = Self::not_elites(current, &self.params)
                 .choose(&mut self.rng)
                 .unwrap();
 
-            for gene in 0..self.params.member_size.get() {
-                let source_parent = if self.rng.gen::<f64>() < self.params.crossover_bias {
+            for gene in 0..self.params.member_size() {
+                let source_parent = if self.rng.gen::<f64>() < self.params.crossover_bias() {
                     elite_parent
                 } else {
                     non_elite_parent
@@ -140,19 +424,59 @@ impl<'a, R: Rng, D: Decoder> Brkga<'a, D, R> {
         }
     }
 
-    /// Substitute the worse members for randomly generated mutants.
-    fn mutate_current(&mut self) {
-        for mutant in Self::mutants(&mut self.current, &self.params) {
-            self.rng.fill(mutant.keys.as_mut());
+    /// Performs the crossover operation of BRKGA-MP to a new generation.
+    ///
+    /// The parents of each child are sampled without repetition, ranked by their quality
+    /// and each gene is taken from one of them based on the weights of their ranks.
+    fn multi_parent_crossover(&mut self, population: usize, multi_parent: MultiParent) {
+        let current = &self.current[population];
+        let elites = self.params.elites();
+        let non_elite_parents = multi_parent.total_parents - multi_parent.elite_parents;
+
+        let weights = multi_parent.cumulative_weights();
+
+        let mut parents = Vec::with_capacity(multi_parent.total_parents);
+
+        for member in Self::regulars(&mut self.next[population], &self.params) {
+            parents.clear();
+            parents.extend(index::sample(
+                &mut self.rng,
+                elites,
+                multi_parent.elite_parents,
+            ));
+            parents.extend(
+                index::sample(&mut self.rng, current.size() - elites, non_elite_parents)
+                    .into_iter()
+                    .map(|position| elites + position),
+            );
+            // The population is sorted, so the position is the rank.
+            parents.sort_unstable();
+
+            multi_parent::mate(
+                &weights,
+                |rank| current[parents[rank]].keys(),
+                member.keys_mut(),
+                &mut self.rng,
+            );
         }
     }
 
-    fn recompute_current(&mut self) {
-        for member in self.current.members.iter_mut() {
-            member.value = self.decoder.decode_value(&member.keys);
+    /// Substitute the worse members for randomly generated mutants.
+    fn mutate_current(&mut self, population: usize) {
+        for mutant in Self::mutants(&mut self.current[population], &self.params) {
+            self.rng.fill(mutant.keys_mut());
         }
+    }
 
-        self.current.sort();
+    /// Decodes the members whose keys changed at once, so a `Parallel` decoder splits them among threads.
+    ///
+    /// Elites carried over from the previous generation keep their values.
+    fn recompute_current(&mut self, population: usize) {
+        let objective = self.objective();
+        let population = &mut self.current[population];
+        self.evaluations += parallel::decode_changed(self.decoder, &mut population.members);
+
+        population.sort_for(objective);
     }
 
     /// Returns the number of the current generation.
@@ -160,82 +484,128 @@ impl<'a, R: Rng, D: Decoder> Brkga<'a, D, R> {
         self.generations
     }
 
-    /// Returns the current [Population] held by the algorithm.
+    /// Returns the first of the current [Population]s.
+    #[deprecated(note = "use `current_populations`, which gives every population")]
     pub fn current_population(&self) -> &BrkgaPopulation<D> {
+        &self.current[0]
+    }
+
+    /// Returns the current [Population]s held by the algorithm.
+    pub fn current_populations(&self) -> &[BrkgaPopulation<D>] {
         &self.current
     }
 
-    /// Returns a reference for the best [Member] at this moment.
+    /// The direction of the problem being solved. See [Problem::objective].
+    fn objective(&self) -> Objective {
+        self.decoder.problem().objective()
+    }
+
+    /// Returns a reference for the best [Member] among all populations at this moment.
     pub fn best(&self) -> &BrkgaMember<D> {
-        &self.current[0]
+        self.current
+            .iter()
+            .map(|population| &population[0])
+            .reduce(|best, candidate| {
+                match self.objective().compare(candidate.value(), best.value()) {
+                    Comparison::Better => candidate,
+                    _ => best,
+                }
+            })
+            .expect("BRKGA always has at least one population")
     }
 
     fn regulars<'b>(
         population: &'b mut BrkgaPopulation<D>,
         p: &Params,
     ) -> &'b mut [BrkgaMember<D>] {
-        let regulars = { p.elites..(p.population_size.get() - p.mutants) };
+        let regulars = { p.elites()..(p.population_size() - p.mutants()) };
         &mut population[regulars]
     }
 
     fn mutants<'b>(population: &'b mut BrkgaPopulation<D>, p: &Params) -> &'b mut [BrkgaMember<D>] {
-        let mutants = (p.population_size.get() - p.mutants)..p.population_size.get();
+        let mutants = (p.population_size() - p.mutants())..p.population_size();
         &mut population[mutants]
     }
 
     /// A slice with the best [Member]s.
     fn elites<'b>(population: &'b BrkgaPopulation<D>, p: &Params) -> &'b [BrkgaMember<D>] {
-        &population[..p.elites]
+        &population[..p.elites()]
     }
 
     /// A slice with the [Member]s which aren't elites.
     fn not_elites<'b>(population: &'b BrkgaPopulation<D>, p: &Params) -> &'b [BrkgaMember<D>] {
-        &population[p.elites..]
+        &population[p.elites()..]
     }
 }
 
-impl<'a, D, R, SC, H> Solver<SC, H> for Brkga<'a, D, R>
+impl<'a, D, R, B, SC, H> Solver<SC, H> for Brkga<'a, D, R, B>
 where
     D: Decoder,
     R: Rng,
+    B: MemberBuilder<D, R>,
     SC: StopCriterion<D::P>,
     H: BrkgaHook<D>,
 {
     type P = D::P;
 
+    fn objective(&self) -> Objective {
+        Brkga::objective(self)
+    }
+
+    fn evaluations(&self) -> usize {
+        Brkga::evaluations(self)
+    }
+
     fn iterate(&mut self, _: &mut SC, hook: &mut H) -> Option<Evaluation<Self::P>> {
+        let restarts = self.restarts;
+
         self.evolve();
-        hook.evolved(&self.current);
+        hook.populations_evolved(&self.current);
+
+        if self.restarts > restarts {
+            hook.restarted(self.params.restart_strategy(), &self.current);
+        }
 
-        let solution = self.decoder.decode(&self.best().keys);
+        let solution = self.decoder.decode(self.best().keys());
         let evaluation = self.decoder.problem().objective_function(solution);
 
         Some(evaluation)
     }
 }
 
-// TODO add constructor to validate the input
-/// The parameters needed to run the BRKGA algorithm
-#[derive(Debug, Clone, Copy)]
-pub struct Params {
-    /// Size of population, which must be greater than 0.
-    pub population_size: NonZeroUsize,
-    /// Size of a member in a population. The [RandomKey][super::RandomKey] slice will have this size.
-    pub member_size: NonZeroUsize,
-    /// Number of elites (best solutions) in a population.
-    pub elites: usize,
-    /// Number of mutants that will be generated from a generation to another.
-    pub mutants: usize,
-    /// It defines how probable is to choose a gene from the elite parent.
-    ///
-    /// Should be a value in \[0.5, 1.0\]
-    pub crossover_bias: f64,
+/// Incumbents are [encoded][Decoder::encode] and [injected][Brkga::inject] into every population.
+/// They're ignored by decoders which can't encode.
+impl<'a, D, R, B> AcceptsIncumbent<D::P> for Brkga<'a, D, R, B>
+where
+    D: Decoder,
+    R: Rng,
+    B: MemberBuilder<D, R>,
+{
+    fn accept_incumbent(&mut self, incumbent: &Evaluation<D::P>) {
+        if let Some(keys) = self.decoder.encode(incumbent.solution()) {
+            for population in 0..self.current.len() {
+                self.inject(keys.clone(), population);
+            }
+        }
+    }
 }
 
 /// A type which can hook into BRKGA-specific events.
 pub trait BrkgaHook<D: Decoder>: IterHook<D::P> {
-    /// Called right after an evolution is performed. `population` is the most recent generation.
-    fn evolved(&mut self, _: &BrkgaPopulation<D>) {}
+    /// Called right after an evolution is performed. `population` is the first of the most recent generation.
+    #[deprecated(note = "implement `populations_evolved`, which is given every population")]
+    fn evolved(&mut self, _population: &BrkgaPopulation<D>) {}
+
+    /// Called right after an evolution is performed. `populations` are the most recent generation.
+    ///
+    /// By default, it calls [evolved][Self::evolved] with the first population, as it was before islands.
+    fn populations_evolved(&mut self, populations: &[BrkgaPopulation<D>]) {
+        #[allow(deprecated)]
+        self.evolved(&populations[0]);
+    }
+
+    /// Called after the populations are restarted through `strategy` due to stagnation.
+    fn restarted(&mut self, _strategy: RestartStrategy, _populations: &[BrkgaPopulation<D>]) {}
 }
 
 /// A hook that does nothing at all.
@@ -244,4 +614,469 @@ pub struct EmptyHook;
 
 impl<D: Decoder> BrkgaHook<D> for EmptyHook {}
 
+impl<D: Decoder> BrkgaHook<D> for Record<<D::P as Problem>::Value> {}
+
 impl<P: Problem> IterHook<P> for EmptyHook {}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use crate::core::stop_criterion::{EvaluationCriterion, IterCriterion};
+
+    use super::{test_helpers::KeySum, *};
+
+    /// Counts how many times members were decoded.
+    #[derive(Default)]
+    struct Counting(AtomicUsize);
+
+    impl Decoder for Counting {
+        type P = KeySum;
+
+        fn decode(&self, member: &[RandomKey]) -> Vec<RandomKey> {
+            self.0.fetch_add(1, Ordering::Relaxed);
+            KeySum.decode(member)
+        }
+
+        fn problem(&self) -> &Self::P {
+            &KeySum
+        }
+    }
+
+    fn params(populations: usize) -> Params {
+        Params::builder(10, 5)
+            .elites(2)
+            .mutants(2)
+            .populations(populations)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn best_is_taken_among_all_populations() {
+        let decoder = KeySum;
+        let rng = StdRng::seed_from_u64(0);
+        let mut brkga = Brkga::new(&decoder, rng, params(3), RandomMemberBuilder);
+
+        for _ in 0..10 {
+            brkga.evolve();
+        }
+
+        let best = brkga
+            .current_populations()
+            .iter()
+            .map(|population| population[0].value())
+            .min()
+            .unwrap();
+
+        assert_eq!(brkga.current_populations().len(), 3);
+        assert_eq!(brkga.best().value(), best);
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn parallel_decoding_gives_the_same_populations() {
+        let parallel = crate::metaheuristics::genetic::parallel::Parallel::new(KeySum);
+        let mut serial = Brkga::new(
+            &KeySum,
+            StdRng::seed_from_u64(0),
+            params(3),
+            RandomMemberBuilder,
+        );
+        let mut parallel = Brkga::new(
+            &parallel,
+            StdRng::seed_from_u64(0),
+            params(3),
+            RandomMemberBuilder,
+        );
+
+        for _ in 0..10 {
+            serial.evolve();
+            parallel.evolve();
+        }
+
+        for (a, b) in serial
+            .current_populations()
+            .iter()
+            .zip(parallel.current_populations())
+        {
+            for (a, b) in a.members.iter().zip(&b.members) {
+                assert_eq!(a.keys(), b.keys());
+                assert_eq!(a.value(), b.value());
+            }
+        }
+        assert_eq!(serial.evaluations(), parallel.evaluations());
+    }
+
+    #[test]
+    fn incumbents_are_injected() {
+        let rng = StdRng::seed_from_u64(0);
+        let mut brkga = Brkga::new(&KeySum, rng, params(2), RandomMemberBuilder);
+
+        brkga.accept_incumbent(&KeySum.objective_function(vec![0.0; 5]));
+
+        for population in brkga.current_populations() {
+            assert_eq!(population[0].value(), 0);
+        }
+    }
+
+    #[test]
+    fn exchange_spreads_the_elites() {
+        let decoder = KeySum;
+        let rng = StdRng::seed_from_u64(0);
+        let mut brkga = Brkga::new(&decoder, rng, params(2), RandomMemberBuilder);
+
+        let bests = brkga
+            .current_populations()
+            .iter()
+            .map(|population| population[0].value())
+            .collect::<Vec<_>>();
+
+        brkga.exchange_elite(1);
+
+        for population in brkga.current_populations() {
+            for best in bests.iter() {
+                assert!(population.members.iter().any(|m| m.value() == *best));
+            }
+        }
+    }
+
+    #[test]
+    fn exchanged_members_come_from_the_other_population() {
+        let rng = StdRng::seed_from_u64(0);
+        let mut brkga = Brkga::new(&KeySum, rng, params(2), RandomMemberBuilder);
+        let emigrants = brkga
+            .current_populations()
+            .iter()
+            .map(|population| population.members[..6].to_vec())
+            .collect::<Vec<_>>();
+
+        // The 6 members received by the first population replace 2 of the ones it sends.
+        brkga.exchange_elite(6);
+
+        for (population, emigrants) in brkga
+            .current_populations()
+            .iter()
+            .zip(emigrants.iter().rev())
+        {
+            for emigrant in emigrants {
+                assert!(population
+                    .members
+                    .iter()
+                    .any(|member| member.keys() == emigrant.keys()));
+            }
+        }
+    }
+
+    /// Implements the single population hook of before islands.
+    #[derive(Default)]
+    struct FirstPopulation(Vec<usize>);
+
+    impl IterHook<KeySum> for FirstPopulation {}
+
+    impl BrkgaHook<KeySum> for FirstPopulation {
+        fn evolved(&mut self, population: &BrkgaPopulation<KeySum>) {
+            self.0.push(population[0].value());
+        }
+    }
+
+    #[test]
+    fn single_population_hooks_still_work() {
+        let rng = StdRng::seed_from_u64(0);
+        let mut brkga = Brkga::new(&KeySum, rng, params(2), RandomMemberBuilder);
+        let mut hook = FirstPopulation::default();
+
+        brkga.solve(&mut IterCriterion::new(3), &mut hook);
+
+        assert_eq!(hook.0.len(), 3);
+        #[allow(deprecated)]
+        let first = brkga.current_population()[0].value();
+        assert_eq!(hook.0[2], first);
+    }
+
+    #[test]
+    fn elites_are_not_decoded_again() {
+        let decoder = Counting::default();
+        let rng = StdRng::seed_from_u64(0);
+        let params = params(2);
+        let mut brkga = Brkga::new(&decoder, rng, params, RandomMemberBuilder);
+
+        decoder.0.store(0, Ordering::Relaxed);
+        brkga.evolve();
+
+        let per_population = params.population_size() - params.elites();
+        assert_eq!(
+            decoder.0.load(Ordering::Relaxed),
+            per_population * params.populations()
+        );
+    }
+
+    /// Sorts the keys of the decoded members.
+    struct Sorting;
+
+    impl Decoder for Sorting {
+        type P = KeySum;
+
+        fn decode(&self, member: &[RandomKey]) -> Vec<RandomKey> {
+            KeySum.decode(member)
+        }
+
+        fn decode_value_mut(&self, member: &mut [RandomKey]) -> usize {
+            member.sort_by(|a, b| a.partial_cmp(b).unwrap());
+            self.decode_value(member)
+        }
+
+        fn problem(&self) -> &Self::P {
+            &KeySum
+        }
+    }
+
+    #[test]
+    fn improvements_are_written_back() {
+        let rng = StdRng::seed_from_u64(0);
+        let mut brkga = Brkga::new(&Sorting, rng, params(1), RandomMemberBuilder);
+        brkga.evolve();
+
+        for member in brkga.current_populations()[0].members.iter() {
+            assert!(member.keys().windows(2).all(|pair| pair[0] <= pair[1]));
+        }
+    }
+
+    /// Every member has the same value, so the search never improves.
+    struct Flat;
+
+    impl Decoder for Flat {
+        type P = KeySum;
+
+        fn decode(&self, member: &[RandomKey]) -> Vec<RandomKey> {
+            KeySum.decode(member)
+        }
+
+        fn decode_value(&self, _: &[RandomKey]) -> usize {
+            0
+        }
+
+        fn problem(&self) -> &Self::P {
+            &KeySum
+        }
+    }
+
+    #[derive(Default)]
+    struct RestartCounter(usize);
+
+    impl IterHook<KeySum> for RestartCounter {}
+
+    impl BrkgaHook<Flat> for RestartCounter {
+        fn restarted(&mut self, _: RestartStrategy, _: &[BrkgaPopulation<Flat>]) {
+            self.0 += 1;
+        }
+    }
+
+    #[test]
+    fn stagnation_triggers_restarts() {
+        let rng = StdRng::seed_from_u64(0);
+        let params = Params::builder(10, 5)
+            .elites(2)
+            .mutants(2)
+            .restart(3, RestartStrategy::Shake(0.5))
+            .build()
+            .unwrap();
+        let mut brkga = Brkga::new(&Flat, rng, params, RandomMemberBuilder);
+        let mut hook = RestartCounter::default();
+        let mut stop_criterion = IterCriterion::new(9);
+
+        brkga.solve(&mut stop_criterion, &mut hook);
+
+        assert_eq!(brkga.restarts(), 3);
+        assert_eq!(hook.0, 3);
+    }
+
+    #[test]
+    fn reinitialization_keeps_the_elites() {
+        let rng = StdRng::seed_from_u64(0);
+        let params = Params::builder(10, 5)
+            .elites(2)
+            .mutants(2)
+            .restart(1, RestartStrategy::Reinitialize)
+            .build()
+            .unwrap();
+        let mut brkga = Brkga::new(&KeySum, rng, params, RandomMemberBuilder);
+        let elites = brkga.current_populations()[0].members[..2]
+            .iter()
+            .map(|elite| elite.keys().to_vec())
+            .collect::<Vec<_>>();
+
+        brkga.restart();
+
+        for elite in elites {
+            assert!(brkga.current_populations()[0]
+                .members
+                .iter()
+                .any(|member| member.keys() == elite));
+        }
+    }
+
+    #[test]
+    fn relinking_never_worsens_the_population() {
+        let rng = StdRng::seed_from_u64(0);
+        let relinking = PathRelinking {
+            kind: RelinkingKind::Permutation,
+            min_distance: 0.0,
+            block_size: 2,
+        };
+        let params = Params::builder(10, 5)
+            .elites(3)
+            .mutants(2)
+            .path_relinking(1, relinking)
+            .build()
+            .unwrap();
+        let mut brkga = Brkga::new(&KeySum, rng, params, RandomMemberBuilder);
+        let values = |brkga: &Brkga<KeySum, StdRng>| {
+            brkga.current_populations()[0]
+                .members
+                .iter()
+                .map(|member| member.value())
+                .collect::<Vec<_>>()
+        };
+        let before = values(&brkga);
+
+        brkga.relink();
+
+        let after = values(&brkga);
+        assert!(after.windows(2).all(|pair| pair[0] <= pair[1]));
+        assert!(before.iter().zip(after.iter()).all(|(b, a)| a <= b));
+    }
+
+    #[test]
+    fn relinked_members_are_decoded_once() {
+        let decoder = Counting::default();
+        let rng = StdRng::seed_from_u64(0);
+        // Only the first block differs from the guide before reaching it, so there's a single intermediate.
+        let relinking = PathRelinking {
+            kind: RelinkingKind::Direct,
+            min_distance: 0.0,
+            block_size: 4,
+        };
+        let params = Params::builder(10, 5)
+            .elites(3)
+            .mutants(2)
+            .path_relinking(1, relinking)
+            .build()
+            .unwrap();
+        let mut brkga = Brkga::new(&decoder, rng, params, RandomMemberBuilder);
+        let worst = brkga.current_populations()[0].members[9].value();
+        let evaluations = brkga.evaluations();
+
+        decoder.0.store(0, Ordering::Relaxed);
+        brkga.relink();
+
+        let population = &brkga.current_populations()[0];
+        assert!(population.members[9].value() < worst);
+        assert_eq!(decoder.0.load(Ordering::Relaxed), 1);
+        assert_eq!(brkga.evaluations(), evaluations + 1);
+        assert!(population.members.windows(2).all(|pair| pair[0] <= pair[1]));
+    }
+
+    #[test]
+    fn seeds_and_injected_members_are_used() {
+        let rng = StdRng::seed_from_u64(0);
+        let zeros = vec![0.0; 5].into_boxed_slice();
+        let member_builder = SeededMemberBuilder::new([zeros.clone()], RandomMemberBuilder);
+        let mut brkga = Brkga::new(&KeySum, rng, params(2), member_builder);
+
+        assert_eq!(brkga.current_populations()[0][0].keys(), &zeros[..]);
+        assert_ne!(brkga.current_populations()[1][0].keys(), &zeros[..]);
+
+        brkga.inject(zeros.clone(), 1);
+
+        let population = &brkga.current_populations()[1];
+        assert_eq!(population[0].keys(), &zeros[..]);
+        assert_eq!(population.size(), 10);
+    }
+
+    #[test]
+    fn decodes_are_counted_as_evaluations() {
+        let rng = StdRng::seed_from_u64(0);
+        let mut brkga = Brkga::new(&KeySum, rng, params(1), RandomMemberBuilder);
+        let mut stop_criterion = EvaluationCriterion::new(50);
+
+        assert_eq!(brkga.evaluations(), 10);
+        brkga.solve(&mut stop_criterion, &mut EmptyHook);
+
+        // Elites aren't decoded again, so each generation decodes 8 members.
+        assert_eq!(brkga.evaluations(), 50);
+        assert_eq!(stop_criterion.evaluations(), 50);
+        assert_eq!(stop_criterion.current_iter(), 5);
+    }
+
+    #[test]
+    fn multi_parent_crossover_evolves() {
+        let rng = StdRng::seed_from_u64(0);
+        let multi_parent = MultiParent {
+            total_parents: 3,
+            elite_parents: 2,
+            bias: BiasFunction::Logarithmic,
+        };
+        let params = Params::builder(10, 5)
+            .elites(2)
+            .mutants(2)
+            .multi_parent(multi_parent)
+            .build()
+            .unwrap();
+        let mut brkga = Brkga::new(&KeySum, rng, params, RandomMemberBuilder);
+        let initial = brkga.best().value();
+
+        brkga.solve(&mut IterCriterion::new(50), &mut EmptyHook);
+
+        assert!(brkga.best().value() <= initial);
+        assert_eq!(brkga.current_populations()[0].members.len(), 10);
+    }
+
+    /// [KeySum] maximized at runtime.
+    struct Reversed;
+
+    impl Decoder for Reversed {
+        type P = Reversed;
+
+        fn decode(&self, member: &[RandomKey]) -> Vec<RandomKey> {
+            member.to_vec()
+        }
+
+        fn problem(&self) -> &Self::P {
+            self
+        }
+    }
+
+    impl Problem for Reversed {
+        const OBJECTIVE: Objective = Objective::Min;
+
+        type Solution = Vec<RandomKey>;
+
+        type Value = usize;
+
+        fn objective_function(&self, solution: Self::Solution) -> Evaluation<Self> {
+            let value = (solution.iter().sum::<f64>() * 1000.0) as usize;
+            Evaluation::new(solution, value)
+        }
+
+        fn objective(&self) -> Objective {
+            Objective::Max
+        }
+    }
+
+    #[test]
+    fn runtime_objective_is_followed() {
+        let rng = StdRng::seed_from_u64(0);
+        let mut brkga = Brkga::new(&Reversed, rng, params(1), RandomMemberBuilder);
+        let initial = brkga.best().value();
+
+        brkga.solve(&mut IterCriterion::new(20), &mut EmptyHook);
+
+        let members = &brkga.current_populations()[0].members;
+        assert!(members.windows(2).all(|w| w[0].value() >= w[1].value()));
+        assert!(brkga.best().value() >= initial);
+        assert_eq!(brkga.best().value(), members[0].value());
+    }
+}
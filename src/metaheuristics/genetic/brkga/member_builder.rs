@@ -1,18 +1,34 @@
+use std::collections::VecDeque;
+
 use super::BrkgaMember;
 
 use rand::Rng;
 
-use super::super::Decoder;
+use super::super::{parallel, Decoder, RandomKey};
 
+/// What is available to a [MemberBuilder] when creating members.
 #[non_exhaustive]
 pub struct Ctx<'a, D, R> {
+    /// The decoder used by the BRKGA.
     pub decoder: &'a D,
+    /// The BRKGA's RNG.
     pub rng: &'a mut R,
+    /// The number of keys in a member.
     pub member_size: usize,
 }
 
+/// Creates the members of a [Brkga][super::Brkga]'s populations. They must be already decoded.
+///
+/// It's used to build the initial populations and to [reinitialize][super::RestartStrategy::Reinitialize]
+/// them on restarts. Closures with the same signature as [build][MemberBuilder::build] are member builders too.
 pub trait MemberBuilder<D: Decoder, R: Rng> {
+    /// Creates a new member. `member_number` is its position in the population being built.
     fn build(&mut self, ctx: &mut Ctx<'_, D, R>, member_number: usize) -> BrkgaMember<D>;
+
+    /// Creates `size` members at once.
+    fn build_population(&mut self, ctx: &mut Ctx<'_, D, R>, size: usize) -> Vec<BrkgaMember<D>> {
+        (0..size).map(|number| self.build(ctx, number)).collect()
+    }
 }
 
 impl<D: Decoder, R: Rng, T: FnMut(&mut Ctx<'_, D, R>, usize) -> BrkgaMember<D>> MemberBuilder<D, R>
@@ -26,15 +42,105 @@ impl<D: Decoder, R: Rng, T: FnMut(&mut Ctx<'_, D, R>, usize) -> BrkgaMember<D>>
 /// Generates random members by using the BRKGA's RNG to generate floats.
 pub struct RandomMemberBuilder;
 
+impl RandomMemberBuilder {
+    fn keys<D, R: Rng>(ctx: &mut Ctx<'_, D, R>) -> Box<[f64]> {
+        let mut k = vec![0.0; ctx.member_size].into_boxed_slice();
+        ctx.rng.fill(k.as_mut());
+        k
+    }
+}
+
 impl<R: Rng, D: Decoder> MemberBuilder<D, R> for RandomMemberBuilder {
     fn build(&mut self, ctx: &mut Ctx<'_, D, R>, _: usize) -> BrkgaMember<D> {
-        let keys = {
-            let mut k = vec![0.0; ctx.member_size].into_boxed_slice();
-            ctx.rng.fill(k.as_mut());
-            k
-        };
-
-        let value = ctx.decoder.decode_value(&keys);
-        BrkgaMember::<D> { keys, value }
+        let mut keys = Self::keys(ctx);
+
+        let value = ctx.decoder.decode_value_mut(&mut keys);
+        BrkgaMember::<D>::new(keys, value)
+    }
+
+    /// The keys are generated in sequence, so the RNG gives the same results,
+    /// but they're decoded at once through [Decoder::decode_values_mut], e.g. in parallel.
+    fn build_population(&mut self, ctx: &mut Ctx<'_, D, R>, size: usize) -> Vec<BrkgaMember<D>> {
+        let mut keys = (0..size).map(|_| Self::keys(ctx)).collect::<Vec<_>>();
+
+        let values = parallel::decode_all(ctx.decoder, &mut keys);
+
+        keys.into_iter()
+            .zip(values)
+            .map(|(keys, value)| BrkgaMember::<D>::new(keys, value))
+            .collect()
+    }
+}
+
+/// Builds members from the given keys, e.g. chromosomes saved from an earlier run or
+/// encoded from the solution of a constructive heuristic, before falling back to another builder.
+///
+/// Seeds are consumed in the order given, so they go to the first populations built. Once they're over,
+/// which includes [reinitializations][super::RestartStrategy::Reinitialize], members come from `fallback`.
+///
+/// ```
+/// # use optimum::metaheuristics::genetic::brkga::{RandomMemberBuilder, SeededMemberBuilder};
+/// let greedy = vec![0.1, 0.5, 0.9].into_boxed_slice();
+/// let member_builder = SeededMemberBuilder::new([greedy], RandomMemberBuilder);
+/// ```
+pub struct SeededMemberBuilder<B = RandomMemberBuilder> {
+    seeds: VecDeque<Box<[RandomKey]>>,
+    fallback: B,
+}
+
+impl<B> SeededMemberBuilder<B> {
+    /// Creates a builder which uses `seeds` first and then `fallback`.
+    pub fn new<I>(seeds: I, fallback: B) -> Self
+    where
+        I: IntoIterator<Item = Box<[RandomKey]>>,
+    {
+        Self {
+            seeds: seeds.into_iter().collect(),
+            fallback,
+        }
+    }
+
+    /// Number of seeds which weren't used yet.
+    pub fn remaining(&self) -> usize {
+        self.seeds.len()
+    }
+}
+
+impl<D: Decoder, R: Rng, B: MemberBuilder<D, R>> MemberBuilder<D, R> for SeededMemberBuilder<B> {
+    fn build(&mut self, ctx: &mut Ctx<'_, D, R>, member_number: usize) -> BrkgaMember<D> {
+        match self.seeds.pop_front() {
+            Some(mut keys) => {
+                assert_eq!(
+                    keys.len(),
+                    ctx.member_size,
+                    "seeds must have one key per gene"
+                );
+
+                let value = ctx.decoder.decode_value_mut(&mut keys);
+                BrkgaMember::<D>::new(keys, value)
+            }
+            None => self.fallback.build(ctx, member_number),
+        }
+    }
+
+    /// Seeds are decoded at once through [Decoder::decode_values_mut], and the rest of
+    /// the population comes from [build_population][MemberBuilder::build_population] of the fallback.
+    fn build_population(&mut self, ctx: &mut Ctx<'_, D, R>, size: usize) -> Vec<BrkgaMember<D>> {
+        let seeded = size.min(self.seeds.len());
+        let mut keys = self.seeds.drain(..seeded).collect::<Vec<_>>();
+        assert!(
+            keys.iter().all(|keys| keys.len() == ctx.member_size),
+            "seeds must have one key per gene"
+        );
+
+        let values = parallel::decode_all(ctx.decoder, &mut keys);
+
+        let mut members = keys
+            .into_iter()
+            .zip(values)
+            .map(|(keys, value)| BrkgaMember::<D>::new(keys, value))
+            .collect::<Vec<_>>();
+        members.extend(self.fallback.build_population(ctx, size - seeded));
+        members
     }
 }
@@ -0,0 +1,146 @@
+use rand::Rng;
+
+use crate::metaheuristics::genetic::RandomKey;
+
+/// Configures the multi-parent crossover of BRKGA-MP. See [ParamsBuilder::multi_parent][super::ParamsBuilder::multi_parent].
+///
+/// Each child has `total_parents` parents, `elite_parents` of them being elites. The parents are ranked by
+/// their quality and each gene comes from the parent of rank `r` (starting from 1) with a probability
+/// proportional to `bias.weight(r)`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MultiParent {
+    /// The number of parents of each child, which must be at least 2.
+    pub total_parents: usize,
+    /// How many of the parents are elites. There must be at least one non elite parent, as in the
+    /// two-parent crossover, so elites don't only breed among themselves and the population keeps its diversity.
+    pub elite_parents: usize,
+    /// How the parents are favored by their rank.
+    pub bias: BiasFunction,
+}
+
+/// Rank-based weights for choosing the parent which gives each gene in the [MultiParent] crossover.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BiasFunction {
+    /// Every parent has the same weight.
+    Constant,
+    /// `1 / r`
+    Linear,
+    /// `1 / r²`
+    Quadratic,
+    /// `e^(-r)`
+    Exponential,
+    /// `1 / ln(r + 1)`
+    Logarithmic,
+}
+
+impl BiasFunction {
+    /// The weight of the parent with rank `rank`, starting from 1.
+    pub fn weight(self, rank: usize) -> f64 {
+        let r = rank as f64;
+
+        match self {
+            BiasFunction::Constant => 1.0,
+            BiasFunction::Linear => 1.0 / r,
+            BiasFunction::Quadratic => 1.0 / (r * r),
+            BiasFunction::Exponential => (-r).exp(),
+            BiasFunction::Logarithmic => 1.0 / (r + 1.0).ln(),
+        }
+    }
+}
+
+impl MultiParent {
+    /// The cumulative weights of the parents, in order of rank.
+    pub(super) fn cumulative_weights(&self) -> Vec<f64> {
+        (1..=self.total_parents)
+            .scan(0.0, |total, rank| {
+                *total += self.bias.weight(rank);
+                Some(*total)
+            })
+            .collect()
+    }
+}
+
+/// Takes each key of `child` from one of the parents, given by their rank starting from zero, with probabilities
+/// proportional to the weights of their ranks, whose `cumulative_weights` are given.
+pub(super) fn mate<'a, R: Rng>(
+    cumulative_weights: &[f64],
+    parent: impl Fn(usize) -> &'a [RandomKey],
+    child: &mut [RandomKey],
+    rng: &mut R,
+) {
+    let total_weight = cumulative_weights[cumulative_weights.len() - 1];
+
+    for (gene, key) in child.iter_mut().enumerate() {
+        let choice = rng.gen::<f64>() * total_weight;
+        let rank = cumulative_weights
+            .iter()
+            .position(|&weight| choice < weight)
+            .unwrap_or(cumulative_weights.len() - 1);
+
+        *key = parent(rank)[gene];
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+
+    #[test]
+    fn weights_decrease_with_rank() {
+        for bias in [
+            BiasFunction::Linear,
+            BiasFunction::Quadratic,
+            BiasFunction::Exponential,
+            BiasFunction::Logarithmic,
+        ] {
+            assert!(bias.weight(1) > bias.weight(2));
+        }
+
+        let config = MultiParent {
+            total_parents: 3,
+            elite_parents: 1,
+            bias: BiasFunction::Constant,
+        };
+        assert_eq!(config.cumulative_weights(), [1.0, 2.0, 3.0]);
+    }
+
+    #[test]
+    fn keys_come_from_the_parents_chosen_by_weight() {
+        let config = MultiParent {
+            total_parents: 3,
+            elite_parents: 1,
+            bias: BiasFunction::Linear,
+        };
+        let weights = config.cumulative_weights();
+        // Every key of a parent is its rank, so each key of the child tells where it came from.
+        let parents = [[0.0; 3000], [1.0; 3000], [2.0; 3000]];
+        let mut child = [0.0; 3000];
+        let mut rng = StdRng::seed_from_u64(0);
+
+        mate(
+            &weights,
+            |rank| &parents[rank],
+            &mut child,
+            &mut rng.clone(),
+        );
+
+        for key in child {
+            let choice = rng.gen::<f64>() * weights[2];
+            let expected = weights.iter().position(|&weight| choice < weight).unwrap();
+            assert_eq!(key, expected as RandomKey);
+        }
+
+        // The weights are 1, 1/2 and 1/3, so the parents give 6/11, 3/11 and 2/11 of the keys.
+        for (rank, share) in [6.0 / 11.0, 3.0 / 11.0, 2.0 / 11.0].into_iter().enumerate() {
+            let keys = child
+                .iter()
+                .filter(|&&key| key == rank as RandomKey)
+                .count();
+            assert!((keys as f64 / child.len() as f64 - share).abs() < 0.03);
+        }
+    }
+}
@@ -0,0 +1,622 @@
+use std::num::NonZeroUsize;
+
+use thiserror::Error;
+
+use super::{MultiParent, PathRelinking};
+
+/// The parameters needed to run the BRKGA algorithm.
+///
+/// It can only be created through [Params::builder], which validates the combination of values given.
+///
+/// ```
+/// # use optimum::metaheuristics::genetic::brkga::Params;
+/// let params = Params::builder(100, 20)
+///     .elites_fraction(0.2)
+///     .mutants(10)
+///     .crossover_bias(0.7)
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(params.elites(), 20);
+/// ```
+///
+/// With the `serde` feature, deserialized params go through the [ParamsBuilder] too, so invalid ones are rejected.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "UncheckedParams", into = "UncheckedParams")
+)]
+pub struct Params {
+    population_size: NonZeroUsize,
+    member_size: NonZeroUsize,
+    elites: usize,
+    mutants: usize,
+    crossover_bias: f64,
+    populations: NonZeroUsize,
+    exchange_interval: usize,
+    exchange_count: usize,
+    restart_after: usize,
+    restart_strategy: RestartStrategy,
+    path_relinking_interval: usize,
+    path_relinking: PathRelinking,
+    multi_parent: Option<MultiParent>,
+}
+
+/// How a [Brkga][super::Brkga] escapes from a stagnated search. See [ParamsBuilder::restart].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RestartStrategy {
+    /// Replaces every non elite member by a new one made by the [MemberBuilder][super::MemberBuilder].
+    Reinitialize,
+    /// Perturbs the elites but the best one, replacing each of their keys by a random key with the given probability.
+    ///
+    /// It needs at least two elites, so there's one to be perturbed.
+    Shake(f64),
+}
+
+impl Params {
+    /// Starts the construction of [Params] for populations of `population_size` members with `member_size` keys each.
+    ///
+    /// The other parameters start with the values below, which may be changed through the [ParamsBuilder]:
+    /// - 20% of the population are elites and 10% are mutants.
+    /// - The crossover has one elite and one non elite parent, with a crossover bias of 0.7.
+    /// - There's a single population, so there are no exchanges.
+    /// - There are no restarts and no path relinking.
+    pub fn builder(population_size: usize, member_size: usize) -> ParamsBuilder {
+        ParamsBuilder {
+            population_size,
+            member_size,
+            elites: Amount::Fraction(0.2),
+            mutants: Amount::Fraction(0.1),
+            crossover_bias: 0.7,
+            populations: 1,
+            exchange_interval: 0,
+            exchange_count: 0,
+            restart_after: 0,
+            restart_strategy: RestartStrategy::Reinitialize,
+            path_relinking_interval: 0,
+            path_relinking: PathRelinking::default(),
+            multi_parent: None,
+        }
+    }
+
+    /// Size of population, which is greater than 0.
+    pub fn population_size(&self) -> usize {
+        self.population_size.get()
+    }
+
+    /// Size of a member in a population. The [RandomKey][super::RandomKey] slice will have this size.
+    pub fn member_size(&self) -> usize {
+        self.member_size.get()
+    }
+
+    /// Number of elites (best solutions) in a population.
+    pub fn elites(&self) -> usize {
+        self.elites
+    }
+
+    /// Number of mutants that will be generated from a generation to another.
+    pub fn mutants(&self) -> usize {
+        self.mutants
+    }
+
+    /// It defines how probable is to choose a gene from the elite parent. It's a value in \[0.5, 1.0\].
+    ///
+    /// It isn't used by the [multi-parent crossover][Self::multi_parent].
+    pub fn crossover_bias(&self) -> f64 {
+        self.crossover_bias
+    }
+
+    /// Number of independent populations evolved side by side.
+    pub fn populations(&self) -> usize {
+        self.populations.get()
+    }
+
+    /// Number of generations between each exchange of elites among populations. Zero means it never happens.
+    pub fn exchange_interval(&self) -> usize {
+        self.exchange_interval
+    }
+
+    /// Number of best members each population sends to the others in an exchange.
+    pub fn exchange_count(&self) -> usize {
+        self.exchange_count
+    }
+
+    /// Number of generations without improving the best member before a restart. Zero means it never happens.
+    pub fn restart_after(&self) -> usize {
+        self.restart_after
+    }
+
+    /// What happens on a restart.
+    pub fn restart_strategy(&self) -> RestartStrategy {
+        self.restart_strategy
+    }
+
+    /// Number of generations between each path relinking. Zero means it never happens.
+    pub fn path_relinking_interval(&self) -> usize {
+        self.path_relinking_interval
+    }
+
+    /// How the path relinking is performed.
+    pub fn path_relinking(&self) -> PathRelinking {
+        self.path_relinking
+    }
+
+    /// The multi-parent crossover used instead of the default one, if any.
+    pub fn multi_parent(&self) -> Option<MultiParent> {
+        self.multi_parent
+    }
+}
+
+/// The fields of [Params] as they're serialized, which are validated by the [ParamsBuilder] when deserialized.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct UncheckedParams {
+    population_size: usize,
+    member_size: usize,
+    elites: usize,
+    mutants: usize,
+    crossover_bias: f64,
+    populations: usize,
+    exchange_interval: usize,
+    exchange_count: usize,
+    restart_after: usize,
+    restart_strategy: RestartStrategy,
+    path_relinking_interval: usize,
+    path_relinking: PathRelinking,
+    multi_parent: Option<MultiParent>,
+}
+
+#[cfg(feature = "serde")]
+impl From<Params> for UncheckedParams {
+    fn from(params: Params) -> Self {
+        Self {
+            population_size: params.population_size(),
+            member_size: params.member_size(),
+            elites: params.elites,
+            mutants: params.mutants,
+            crossover_bias: params.crossover_bias,
+            populations: params.populations(),
+            exchange_interval: params.exchange_interval,
+            exchange_count: params.exchange_count,
+            restart_after: params.restart_after,
+            restart_strategy: params.restart_strategy,
+            path_relinking_interval: params.path_relinking_interval,
+            path_relinking: params.path_relinking,
+            multi_parent: params.multi_parent,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<UncheckedParams> for Params {
+    type Error = ParamsError;
+
+    fn try_from(params: UncheckedParams) -> Result<Self, Self::Error> {
+        let mut builder = Params::builder(params.population_size, params.member_size)
+            .elites(params.elites)
+            .mutants(params.mutants)
+            .crossover_bias(params.crossover_bias)
+            .populations(params.populations)
+            .exchange(params.exchange_interval, params.exchange_count)
+            .restart(params.restart_after, params.restart_strategy)
+            .path_relinking(params.path_relinking_interval, params.path_relinking);
+
+        if let Some(multi_parent) = params.multi_parent {
+            builder = builder.multi_parent(multi_parent);
+        }
+
+        builder.build()
+    }
+}
+
+// It isn't a `TypedBuilder` as in `Batch` because building may fail, amounts may be given either directly
+// or as fractions, and some settings only make sense together, e.g. the exchange interval and count.
+/// Builds validated [Params]. See [Params::builder].
+#[derive(Debug, Clone)]
+pub struct ParamsBuilder {
+    population_size: usize,
+    member_size: usize,
+    elites: Amount,
+    mutants: Amount,
+    crossover_bias: f64,
+    populations: usize,
+    exchange_interval: usize,
+    exchange_count: usize,
+    restart_after: usize,
+    restart_strategy: RestartStrategy,
+    path_relinking_interval: usize,
+    path_relinking: PathRelinking,
+    multi_parent: Option<MultiParent>,
+}
+
+/// A number of members, given directly or as a fraction of the population.
+#[derive(Debug, Clone, Copy)]
+enum Amount {
+    Absolute(usize),
+    Fraction(f64),
+}
+
+impl Amount {
+    fn resolve(self, population_size: usize) -> Result<usize, ParamsError> {
+        match self {
+            Amount::Absolute(amount) => Ok(amount),
+            Amount::Fraction(fraction) if (0.0..=1.0).contains(&fraction) => {
+                let amount = (fraction * population_size as f64) as usize;
+                Ok(if fraction > 0.0 {
+                    amount.max(1)
+                } else {
+                    amount
+                })
+            }
+            Amount::Fraction(fraction) => Err(ParamsError::InvalidFraction(fraction)),
+        }
+    }
+}
+
+impl ParamsBuilder {
+    /// Sets the number of elites in a population.
+    pub fn elites(mut self, elites: usize) -> Self {
+        self.elites = Amount::Absolute(elites);
+        self
+    }
+
+    /// Sets the number of elites as a fraction of the population, in \[0.0, 1.0\].
+    ///
+    /// It's rounded down, but a fraction greater than zero gives at least one elite.
+    pub fn elites_fraction(mut self, fraction: f64) -> Self {
+        self.elites = Amount::Fraction(fraction);
+        self
+    }
+
+    /// Sets the number of mutants in a population.
+    pub fn mutants(mut self, mutants: usize) -> Self {
+        self.mutants = Amount::Absolute(mutants);
+        self
+    }
+
+    /// Sets the number of mutants as a fraction of the population, in \[0.0, 1.0\].
+    ///
+    /// It's rounded down, but a fraction greater than zero gives at least one mutant.
+    pub fn mutants_fraction(mut self, fraction: f64) -> Self {
+        self.mutants = Amount::Fraction(fraction);
+        self
+    }
+
+    /// Sets the probability of choosing a gene from the elite parent, which must be in \[0.5, 1.0\].
+    pub fn crossover_bias(mut self, crossover_bias: f64) -> Self {
+        self.crossover_bias = crossover_bias;
+        self
+    }
+
+    /// Sets the number of independent populations.
+    pub fn populations(mut self, populations: usize) -> Self {
+        self.populations = populations;
+        self
+    }
+
+    /// Makes the populations send their `count` best members to the others every `interval` generations.
+    pub fn exchange(mut self, interval: usize, count: usize) -> Self {
+        self.exchange_interval = interval;
+        self.exchange_count = count;
+        self
+    }
+
+    /// Applies `strategy` whenever the best member isn't improved for `after` generations in sequence.
+    pub fn restart(mut self, after: usize, strategy: RestartStrategy) -> Self {
+        self.restart_after = after;
+        self.restart_strategy = strategy;
+        self
+    }
+
+    /// Performs the path relinking defined by `relinking` in each population every `interval` generations.
+    pub fn path_relinking(mut self, interval: usize, relinking: PathRelinking) -> Self {
+        self.path_relinking_interval = interval;
+        self.path_relinking = relinking;
+        self
+    }
+
+    /// Replaces the crossover between one elite and one non elite parent by the [MultiParent] one.
+    pub fn multi_parent(mut self, multi_parent: MultiParent) -> Self {
+        self.multi_parent = Some(multi_parent);
+        self
+    }
+
+    /// Validates the parameters given.
+    pub fn build(self) -> Result<Params, ParamsError> {
+        let population_size =
+            NonZeroUsize::new(self.population_size).ok_or(ParamsError::EmptyPopulation)?;
+        let member_size = NonZeroUsize::new(self.member_size).ok_or(ParamsError::EmptyMember)?;
+        let populations = NonZeroUsize::new(self.populations).ok_or(ParamsError::NoPopulations)?;
+
+        let elites = self.elites.resolve(self.population_size)?;
+        let mutants = self.mutants.resolve(self.population_size)?;
+
+        if elites == 0 {
+            return Err(ParamsError::NoElites);
+        }
+
+        // Crossover needs at least one non elite parent.
+        if elites >= self.population_size {
+            return Err(ParamsError::TooManyElites {
+                elites,
+                population_size: self.population_size,
+            });
+        }
+
+        if elites + mutants > self.population_size {
+            return Err(ParamsError::TooManyMembers {
+                elites,
+                mutants,
+                population_size: self.population_size,
+            });
+        }
+
+        if !(0.5..=1.0).contains(&self.crossover_bias) {
+            return Err(ParamsError::InvalidCrossoverBias(self.crossover_bias));
+        }
+
+        let exchanges = self.populations > 1 && self.exchange_interval > 0;
+        if exchanges && self.exchange_count * (self.populations - 1) >= self.population_size {
+            return Err(ParamsError::TooManyExchanged {
+                count: self.exchange_count,
+                populations: self.populations,
+                population_size: self.population_size,
+            });
+        }
+
+        if let RestartStrategy::Shake(probability) = self.restart_strategy {
+            if !(probability > 0.0 && probability <= 1.0) {
+                return Err(ParamsError::InvalidShakeProbability(probability));
+            }
+
+            if elites < 2 {
+                return Err(ParamsError::NothingToShake);
+            }
+        }
+
+        if !(0.0..=1.0).contains(&self.path_relinking.min_distance) {
+            return Err(ParamsError::InvalidRelinkingDistance(
+                self.path_relinking.min_distance,
+            ));
+        }
+
+        if self.path_relinking.block_size == 0 {
+            return Err(ParamsError::EmptyRelinkingBlock);
+        }
+
+        if let Some(parents) = self.multi_parent {
+            let MultiParent {
+                total_parents,
+                elite_parents,
+                ..
+            } = parents;
+
+            // Like the two-parent crossover, every child has an elite and a non elite parent.
+            if total_parents < 2 || elite_parents == 0 || elite_parents >= total_parents {
+                return Err(ParamsError::InvalidParents {
+                    total_parents,
+                    elite_parents,
+                });
+            }
+
+            if elite_parents > elites
+                || total_parents - elite_parents > self.population_size - elites
+            {
+                return Err(ParamsError::NotEnoughParents {
+                    total_parents,
+                    elite_parents,
+                });
+            }
+        }
+
+        Ok(Params {
+            population_size,
+            member_size,
+            elites,
+            mutants,
+            crossover_bias: self.crossover_bias,
+            populations,
+            exchange_interval: self.exchange_interval,
+            exchange_count: self.exchange_count,
+            restart_after: self.restart_after,
+            restart_strategy: self.restart_strategy,
+            path_relinking_interval: self.path_relinking_interval,
+            path_relinking: self.path_relinking,
+            multi_parent: self.multi_parent,
+        })
+    }
+}
+
+/// The reasons why [ParamsBuilder::build] may reject the parameters given.
+#[derive(Debug, Clone, Copy, PartialEq, Error)]
+pub enum ParamsError {
+    /// The population size is zero.
+    #[error("the population must have at least one member")]
+    EmptyPopulation,
+    /// The member size is zero.
+    #[error("a member must have at least one key")]
+    EmptyMember,
+    /// The number of populations is zero.
+    #[error("there must be at least one population")]
+    NoPopulations,
+    /// There are no elites, so there's no elite parent for the crossover.
+    #[error("there must be at least one elite")]
+    NoElites,
+    /// Every member is an elite, so there's no non elite parent for the crossover.
+    #[error("{elites} elites leave no non elite member in a population of {population_size}")]
+    TooManyElites {
+        /// Number of elites.
+        elites: usize,
+        /// Size of the population.
+        population_size: usize,
+    },
+    /// Elites and mutants together don't fit in the population.
+    #[error(
+        "{elites} elites and {mutants} mutants don't fit in a population of {population_size}"
+    )]
+    TooManyMembers {
+        /// Number of elites.
+        elites: usize,
+        /// Number of mutants.
+        mutants: usize,
+        /// Size of the population.
+        population_size: usize,
+    },
+    /// The crossover bias is outside of \[0.5, 1.0\].
+    #[error("the crossover bias must be in [0.5, 1.0], but it's {0}")]
+    InvalidCrossoverBias(f64),
+    /// A fraction of the population is outside of \[0.0, 1.0\].
+    #[error("a fraction of the population must be in [0.0, 1.0], but it's {0}")]
+    InvalidFraction(f64),
+    /// The members received in an exchange would replace every member of a population.
+    #[error("{populations} populations sending {count} members each don't fit in a population of {population_size}")]
+    TooManyExchanged {
+        /// Number of members each population sends.
+        count: usize,
+        /// Number of populations.
+        populations: usize,
+        /// Size of the population.
+        population_size: usize,
+    },
+    /// The probability of shaking a key is outside of (0.0, 1.0\].
+    #[error("the probability of shaking a key must be in (0.0, 1.0], but it's {0}")]
+    InvalidShakeProbability(f64),
+    /// There's a single elite, which is never shaken, so shaking would do nothing.
+    #[error("shaking needs at least two elites, as the best one is kept")]
+    NothingToShake,
+    /// The minimum distance for path relinking is outside of \[0.0, 1.0\].
+    #[error("the minimum distance for path relinking must be in [0.0, 1.0], but it's {0}")]
+    InvalidRelinkingDistance(f64),
+    /// The path relinking would change no keys between intermediate chromosomes.
+    #[error("the path relinking block size must be greater than zero")]
+    EmptyRelinkingBlock,
+    /// The multi-parent crossover needs at least one elite and one non elite parent.
+    #[error("{total_parents} parents with {elite_parents} elites don't have both elite and non elite parents")]
+    InvalidParents {
+        /// Number of parents.
+        total_parents: usize,
+        /// Number of elite parents.
+        elite_parents: usize,
+    },
+    /// The population doesn't have enough elites or non elites to be the parents.
+    #[error("the population doesn't have enough members for {total_parents} parents with {elite_parents} elites")]
+    NotEnoughParents {
+        /// Number of parents.
+        total_parents: usize,
+        /// Number of elite parents.
+        elite_parents: usize,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metaheuristics::genetic::brkga::BiasFunction;
+
+    #[test]
+    fn fractions_are_resolved() {
+        let params = Params::builder(50, 10)
+            .elites_fraction(0.25)
+            .mutants_fraction(0.1)
+            .build()
+            .unwrap();
+
+        assert_eq!(params.elites(), 12);
+        assert_eq!(params.mutants(), 5);
+    }
+
+    #[test]
+    fn defaults_work_for_small_populations() {
+        let params = Params::builder(4, 10).build().unwrap();
+
+        assert_eq!(params.elites(), 1);
+        assert_eq!(params.mutants(), 1);
+    }
+
+    #[test]
+    fn invalid_combinations_are_rejected() {
+        let builder = || Params::builder(10, 5).elites(2).mutants(2);
+
+        assert_eq!(
+            Params::builder(0, 5).build().unwrap_err(),
+            ParamsError::EmptyPopulation
+        );
+        assert_eq!(
+            builder().elites(0).build().unwrap_err(),
+            ParamsError::NoElites
+        );
+        assert_eq!(
+            builder().elites(10).mutants(0).build().unwrap_err(),
+            ParamsError::TooManyElites {
+                elites: 10,
+                population_size: 10
+            }
+        );
+        assert_eq!(
+            builder().mutants(9).build().unwrap_err(),
+            ParamsError::TooManyMembers {
+                elites: 2,
+                mutants: 9,
+                population_size: 10
+            }
+        );
+        assert_eq!(
+            builder().crossover_bias(0.3).build().unwrap_err(),
+            ParamsError::InvalidCrossoverBias(0.3)
+        );
+        assert_eq!(
+            builder().elites_fraction(1.5).build().unwrap_err(),
+            ParamsError::InvalidFraction(1.5)
+        );
+        assert_eq!(
+            builder()
+                .populations(3)
+                .exchange(10, 5)
+                .build()
+                .unwrap_err(),
+            ParamsError::TooManyExchanged {
+                count: 5,
+                populations: 3,
+                population_size: 10
+            }
+        );
+        assert_eq!(
+            builder()
+                .restart(10, RestartStrategy::Shake(0.0))
+                .build()
+                .unwrap_err(),
+            ParamsError::InvalidShakeProbability(0.0)
+        );
+        assert_eq!(
+            builder()
+                .elites(1)
+                .restart(10, RestartStrategy::Shake(0.5))
+                .build()
+                .unwrap_err(),
+            ParamsError::NothingToShake
+        );
+
+        let parents = |total_parents, elite_parents| MultiParent {
+            total_parents,
+            elite_parents,
+            bias: BiasFunction::Linear,
+        };
+        assert_eq!(
+            builder().multi_parent(parents(3, 3)).build().unwrap_err(),
+            ParamsError::InvalidParents {
+                total_parents: 3,
+                elite_parents: 3
+            }
+        );
+        assert_eq!(
+            builder().multi_parent(parents(5, 3)).build().unwrap_err(),
+            ParamsError::NotEnoughParents {
+                total_parents: 5,
+                elite_parents: 3
+            }
+        );
+        assert!(builder().multi_parent(parents(3, 2)).build().is_ok());
+    }
+}
@@ -0,0 +1,215 @@
+use crate::metaheuristics::genetic::RandomKey;
+
+/// Configures the path relinking between elites. See [ParamsBuilder::path_relinking][super::ParamsBuilder::path_relinking].
+///
+/// Path relinking walks from an elite chromosome (the base) toward another (the guide), one block of keys at a time.
+/// Each intermediate chromosome is decoded and the best of them replaces the worst member of the population,
+/// as long as it's better than it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PathRelinking {
+    /// How the keys of the guide are brought into the base.
+    pub kind: RelinkingKind,
+    /// The minimum [distance][RelinkingKind] between two elites for them to be relinked, in \[0.0, 1.0\].
+    pub min_distance: f64,
+    /// Number of keys (or swaps, for [RelinkingKind::Permutation]) changed between intermediate chromosomes.
+    pub block_size: usize,
+}
+
+impl Default for PathRelinking {
+    fn default() -> Self {
+        Self {
+            kind: RelinkingKind::Direct,
+            min_distance: 0.15,
+            block_size: 1,
+        }
+    }
+}
+
+/// The ways of walking from one chromosome toward another.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RelinkingKind {
+    /// Copies the keys of the guide into the same positions of the base.
+    ///
+    /// The distance is the fraction of keys which are on different halves of \[0, 1) in each chromosome.
+    Direct,
+    /// Swaps keys of the base until it sorts the same way as the guide. It suits decoders which sort the keys.
+    ///
+    /// The distance is the fraction of pairs of keys in a different relative order in each chromosome (Kendall tau).
+    Permutation,
+}
+
+impl RelinkingKind {
+    /// The distance between `a` and `b`, in \[0.0, 1.0\].
+    pub fn distance(self, a: &[RandomKey], b: &[RandomKey]) -> f64 {
+        match self {
+            RelinkingKind::Direct => {
+                let different = a
+                    .iter()
+                    .zip(b)
+                    .filter(|&(a, b)| (*a < 0.5) != (*b < 0.5))
+                    .count();
+
+                different as f64 / a.len() as f64
+            }
+            RelinkingKind::Permutation => {
+                if a.len() < 2 {
+                    return 0.0;
+                }
+
+                let mut discordant = 0;
+                for i in 0..a.len() {
+                    for j in (i + 1)..a.len() {
+                        if (a[i] < a[j]) != (b[i] < b[j]) {
+                            discordant += 1;
+                        }
+                    }
+                }
+
+                let pairs = a.len() * (a.len() - 1) / 2;
+                discordant as f64 / pairs as f64
+            }
+        }
+    }
+}
+
+/// Walks from `base` toward `guide`, returning the best intermediate chromosome and its value.
+///
+/// `better(a, b)` tells if the value `a` is better than `b`.
+pub(super) fn relink<V: Copy>(
+    config: PathRelinking,
+    base: &[RandomKey],
+    guide: &[RandomKey],
+    mut decode: impl FnMut(&[RandomKey]) -> V,
+    better: impl Fn(V, V) -> bool,
+) -> Option<(Box<[RandomKey]>, V)> {
+    let mut current: Box<[RandomKey]> = base.into();
+    let mut best: Option<(Box<[RandomKey]>, V)> = None;
+
+    let mut consider = |current: &[RandomKey]| {
+        // The guide itself is already known.
+        if current == guide {
+            return;
+        }
+
+        let value = decode(current);
+        match &mut best {
+            Some((keys, best)) if better(value, *best) => {
+                keys.copy_from_slice(current);
+                *best = value;
+            }
+            Some(_) => (),
+            None => best = Some((current.into(), value)),
+        }
+    };
+
+    match config.kind {
+        RelinkingKind::Direct => {
+            for start in (0..base.len()).step_by(config.block_size) {
+                let range = start..(start + config.block_size).min(base.len());
+
+                if current[range.clone()] != guide[range.clone()] {
+                    current[range.clone()].copy_from_slice(&guide[range]);
+                    consider(&current);
+                }
+            }
+        }
+        RelinkingKind::Permutation => {
+            let mut order = sorted_positions(&current);
+            let mut rank = vec![0; order.len()];
+            for (r, &position) in order.iter().enumerate() {
+                rank[position] = r;
+            }
+
+            let mut swaps = 0;
+            for (r, wanted) in sorted_positions(guide).into_iter().enumerate() {
+                let found = order[r];
+                if found == wanted {
+                    continue;
+                }
+
+                // Exchanging their keys exchanges their ranks.
+                current.swap(found, wanted);
+                order.swap(r, rank[wanted]);
+                rank.swap(found, wanted);
+
+                swaps += 1;
+                if swaps % config.block_size == 0 {
+                    consider(&current);
+                }
+            }
+
+            if swaps % config.block_size != 0 {
+                consider(&current);
+            }
+        }
+    }
+
+    best
+}
+
+/// The positions of `keys` ordered by their keys.
+fn sorted_positions(keys: &[RandomKey]) -> Vec<usize> {
+    let mut positions = (0..keys.len()).collect::<Vec<_>>();
+    positions.sort_by(|&a, &b| keys[a].total_cmp(&keys[b]));
+    positions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(kind: RelinkingKind) -> PathRelinking {
+        PathRelinking {
+            kind,
+            min_distance: 0.0,
+            block_size: 1,
+        }
+    }
+
+    #[test]
+    fn distances() {
+        let a = [0.1, 0.2, 0.7, 0.9];
+        let b = [0.6, 0.2, 0.1, 0.9];
+
+        assert_eq!(RelinkingKind::Direct.distance(&a, &a), 0.0);
+        assert_eq!(RelinkingKind::Direct.distance(&a, &b), 0.5);
+        assert_eq!(
+            RelinkingKind::Permutation.distance(&a, &[0.4, 0.3, 0.2, 0.1]),
+            1.0
+        );
+    }
+
+    #[test]
+    fn direct_relinking_finds_the_best_intermediate() {
+        let base = [0.0, 0.0, 0.0];
+        let guide = [1.0, -5.0, 1.0];
+        let sum = |keys: &[RandomKey]| (keys.iter().sum::<f64>() * 10.0) as i64;
+
+        let (keys, value) = relink(config(RelinkingKind::Direct), &base, &guide, sum, |a, b| {
+            a < b
+        })
+        .unwrap();
+
+        assert_eq!(&*keys, &[1.0, -5.0, 0.0]);
+        assert_eq!(value, -40);
+    }
+
+    #[test]
+    fn permutation_relinking_reaches_the_guide_order() {
+        let base = [0.1, 0.2, 0.3, 0.4];
+        let guide = [0.9, 0.7, 0.5, 0.3];
+        let mut last = Vec::new();
+
+        relink(
+            config(RelinkingKind::Permutation),
+            &base,
+            &guide,
+            |keys| last = keys.to_vec(),
+            |_, _| false,
+        );
+
+        assert_eq!(RelinkingKind::Permutation.distance(&last, &guide), 0.0);
+    }
+}
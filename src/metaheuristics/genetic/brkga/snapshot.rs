@@ -0,0 +1,257 @@
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::core::Problem;
+
+use super::{
+    super::{population::Member, Decoder, RandomKey},
+    Brkga, MemberBuilder, Params, Population,
+};
+
+use rand::Rng;
+
+/// The state of a [Brkga] run, which may be saved and [restored][Brkga::restore] to continue the same run later.
+///
+/// It keeps the members of every population, the generation counter, the [Params], the RNG and
+/// what's needed for restarts. The RNG must be serializable, e.g. `rand_pcg`'s generators with its `serde1` feature.
+///
+/// Only available with the `serde` feature.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Snapshot<V, R> {
+    populations: Vec<Vec<Member<RandomKey, V>>>,
+    generations: usize,
+    params: Params,
+    rng: R,
+    best_value: V,
+    last_improvement: usize,
+    restarts: usize,
+    #[serde(default)]
+    evaluations: usize,
+}
+
+/// The reasons why [Brkga::restore] may reject a [Snapshot], which happen when it doesn't match its own [Params].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+pub enum SnapshotError {
+    /// The number of populations differs from [Params::populations].
+    #[error("the snapshot has {found} populations, but its params have {expected}")]
+    Populations {
+        /// Number of populations in the params.
+        expected: usize,
+        /// Number of populations in the snapshot.
+        found: usize,
+    },
+    /// A population's size differs from [Params::population_size].
+    #[error("a population has {found} members, but its params have {expected}")]
+    PopulationSize {
+        /// Size of a population in the params.
+        expected: usize,
+        /// Size of the population in the snapshot.
+        found: usize,
+    },
+    /// A member's size differs from [Params::member_size].
+    #[error("a member has {found} keys, but its params have {expected}")]
+    MemberSize {
+        /// Size of a member in the params.
+        expected: usize,
+        /// Size of the member in the snapshot.
+        found: usize,
+    },
+}
+
+impl<V, R> Snapshot<V, R> {
+    /// The generation in which the snapshot was taken.
+    pub fn generations(&self) -> usize {
+        self.generations
+    }
+
+    /// The parameters of the run.
+    pub fn params(&self) -> &Params {
+        &self.params
+    }
+
+    fn check(&self) -> Result<(), SnapshotError> {
+        let populations = self.populations.len();
+        if populations != self.params.populations() {
+            return Err(SnapshotError::Populations {
+                expected: self.params.populations(),
+                found: populations,
+            });
+        }
+
+        for members in &self.populations {
+            if members.len() != self.params.population_size() {
+                return Err(SnapshotError::PopulationSize {
+                    expected: self.params.population_size(),
+                    found: members.len(),
+                });
+            }
+
+            if let Some(member) = members
+                .iter()
+                .find(|member| member.keys().len() != self.params.member_size())
+            {
+                return Err(SnapshotError::MemberSize {
+                    expected: self.params.member_size(),
+                    found: member.keys().len(),
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a, R: Rng, D: Decoder, B: MemberBuilder<D, R>> Brkga<'a, D, R, B> {
+    /// Takes a [Snapshot] of the current state of the run.
+    pub fn snapshot(&self) -> Snapshot<<D::P as Problem>::Value, R>
+    where
+        R: Clone,
+    {
+        Snapshot {
+            populations: self
+                .current
+                .iter()
+                .map(|population| population.members.clone())
+                .collect(),
+            generations: self.generations,
+            params: self.params,
+            rng: self.rng.clone(),
+            best_value: self.best_value,
+            last_improvement: self.last_improvement,
+            restarts: self.restarts,
+            evaluations: self.evaluations,
+        }
+    }
+
+    /// Continues the run saved in `snapshot`. Its members aren't decoded again.
+    ///
+    /// `decoder` must decode the same instance of the problem used when the snapshot was taken.
+    /// `member_builder` is kept for [restarts][Self::restart].
+    ///
+    /// Fails if the populations and members of the snapshot don't match its params, e.g. when it was edited by hand.
+    pub fn restore(
+        decoder: &'a D,
+        snapshot: Snapshot<<D::P as Problem>::Value, R>,
+        member_builder: B,
+    ) -> Result<Self, SnapshotError> {
+        snapshot.check()?;
+
+        let current = snapshot
+            .populations
+            .into_iter()
+            .map(|members| {
+                let mut population = Population::from_members(members);
+                population.sort_for(decoder.problem().objective());
+                population
+            })
+            .collect::<Vec<_>>();
+        let next = current.clone();
+
+        Ok(Self {
+            decoder,
+            rng: snapshot.rng,
+            member_builder,
+            current,
+            next,
+            generations: snapshot.generations,
+            params: snapshot.params,
+            best_value: snapshot.best_value,
+            last_improvement: snapshot.last_improvement,
+            restarts: snapshot.restarts,
+            evaluations: snapshot.evaluations,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::SeedableRng;
+    use rand_pcg::Pcg64;
+
+    use super::super::{test_helpers::KeySum, RandomMemberBuilder};
+    use super::*;
+
+    #[test]
+    fn restored_runs_continue_the_same_way() {
+        let params = Params::builder(10, 5)
+            .elites(2)
+            .mutants(2)
+            .populations(2)
+            .exchange(3, 1)
+            .build()
+            .unwrap();
+        let rng = Pcg64::seed_from_u64(0);
+        let mut brkga = Brkga::new(&KeySum, rng, params, RandomMemberBuilder);
+        for _ in 0..5 {
+            brkga.evolve();
+        }
+
+        let saved = serde_json::to_string(&brkga.snapshot()).unwrap();
+        let snapshot: Snapshot<usize, Pcg64> = serde_json::from_str(&saved).unwrap();
+        assert_eq!(snapshot.generations(), 5);
+        let mut restored = Brkga::restore(&KeySum, snapshot, RandomMemberBuilder).unwrap();
+
+        for _ in 0..5 {
+            brkga.evolve();
+            restored.evolve();
+        }
+
+        assert_eq!(restored.current_generation(), 10);
+        for (a, b) in brkga
+            .current_populations()
+            .iter()
+            .zip(restored.current_populations())
+        {
+            for (a, b) in a.members.iter().zip(&b.members) {
+                assert_eq!(a.keys(), b.keys());
+                assert_eq!(a.value(), b.value());
+            }
+        }
+    }
+
+    #[test]
+    fn invalid_snapshots_are_rejected() {
+        let params = Params::builder(10, 5).build().unwrap();
+
+        let mut edited = serde_json::to_value(params).unwrap();
+        edited["elites"] = 0.into();
+        assert!(serde_json::from_value::<Params>(edited).is_err());
+
+        let brkga = Brkga::new(
+            &KeySum,
+            Pcg64::seed_from_u64(0),
+            params,
+            RandomMemberBuilder,
+        );
+
+        let mut snapshot = brkga.snapshot();
+        snapshot.populations[0].pop();
+        assert_eq!(
+            Brkga::restore(&KeySum, snapshot, RandomMemberBuilder).err(),
+            Some(SnapshotError::PopulationSize {
+                expected: 10,
+                found: 9
+            })
+        );
+
+        let mut snapshot = brkga.snapshot();
+        snapshot.populations[0][3] = Member::new(vec![0.5; 4].into(), 0);
+        assert_eq!(
+            Brkga::restore(&KeySum, snapshot, RandomMemberBuilder).err(),
+            Some(SnapshotError::MemberSize {
+                expected: 5,
+                found: 4
+            })
+        );
+
+        let mut snapshot = brkga.snapshot();
+        snapshot.populations.clear();
+        assert_eq!(
+            Brkga::restore(&KeySum, snapshot, RandomMemberBuilder).err(),
+            Some(SnapshotError::Populations {
+                expected: 1,
+                found: 0
+            })
+        );
+    }
+}
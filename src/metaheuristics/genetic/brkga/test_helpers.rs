@@ -0,0 +1,36 @@
+use crate::{
+    core::{Evaluation, Objective, Problem},
+    metaheuristics::genetic::{Decoder, RandomKey},
+};
+
+/// Minimizes the sum of the keys, which are the solution themselves.
+pub(crate) struct KeySum;
+
+impl Problem for KeySum {
+    const OBJECTIVE: Objective = Objective::Min;
+
+    type Solution = Vec<RandomKey>;
+
+    type Value = usize;
+
+    fn objective_function(&self, solution: Self::Solution) -> Evaluation<Self> {
+        let value = (solution.iter().sum::<f64>() * 1000.0) as usize;
+        Evaluation::new(solution, value)
+    }
+}
+
+impl Decoder for KeySum {
+    type P = Self;
+
+    fn decode(&self, member: &[RandomKey]) -> Vec<RandomKey> {
+        member.to_vec()
+    }
+
+    fn encode(&self, solution: &Vec<RandomKey>) -> Option<Box<[RandomKey]>> {
+        Some(solution.clone().into())
+    }
+
+    fn problem(&self) -> &Self::P {
+        self
+    }
+}
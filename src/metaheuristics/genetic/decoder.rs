@@ -1,26 +1,60 @@
 use crate::core::Problem;
 
-/// [Decoder] defines the behavior needed for Random-Key based algorithms to [decode][Decoder::decode] the member
+/// [Decoder] defines the behavior needed for genetic algorithms to [decode][Decoder::decode] the member
 /// of the population the discover its [Value][Problem::Value] for the target [Problem].
-pub trait Decoder {
+///
+/// The genes are [RandomKey]s by default, which is what [Brkga][super::brkga::Brkga] works with.
+/// Other encodings, e.g. binary or permutations, may be decoded for the [Ga][super::ga::Ga].
+///
+/// Members may be decoded across threads by wrapping the decoder in a `Parallel`, with the `parallel` feature.
+/// See [parallel][super::parallel] for details.
+pub trait Decoder<G = RandomKey> {
     /// The problem being decoded.
     type P: Problem;
 
-    /// A mapping between a slice of [RandomKey]s and a value for [Self::P].
-    ///
-    /// You'll usually convert `member` into a solution for the problem and then
-    /// apply the solution into the problem's objective function.
-    fn decode(&self, member: &[RandomKey]) -> <Self::P as Problem>::Solution;
+    /// A mapping between a slice of genes and a solution for [Self::P].
+    fn decode(&self, member: &[G]) -> <Self::P as Problem>::Solution;
 
-    /// A mapping between a slice of [RandomKey]s and a value for [Self::P].
+    /// A mapping between a slice of genes and a value for [Self::P].
     ///
     /// You'll usually convert `member` into a solution for the problem and then
     /// apply the solution into the problem's objective function.
-    fn decode_value(&self, member: &[RandomKey]) -> <Self::P as Problem>::Value {
+    fn decode_value(&self, member: &[G]) -> <Self::P as Problem>::Value {
         let solution = self.decode(member);
         self.problem().objective_function(solution).value()
     }
 
+    /// Like [decode_value][Self::decode_value], but it's allowed to rewrite `member`.
+    ///
+    /// Decoders which improve the decoded solution, e.g. through a local search, may encode
+    /// the improved solution back into the genes and return its value. This is known as
+    /// Lamarckian evolution: the improvements are inherited by the offspring.
+    ///
+    /// Algorithms such as [Brkga][super::brkga::Brkga] always decode their members through it.
+    /// By default, the genes are left untouched.
+    fn decode_value_mut(&self, member: &mut [G]) -> <Self::P as Problem>::Value {
+        self.decode_value(member)
+    }
+
+    /// The inverse of [decode][Self::decode]: genes which are decoded into `solution`, or one close to it.
+    ///
+    /// It lets solutions found elsewhere, e.g. by another solver of a [Portfolio][crate::core::solver::Portfolio],
+    /// be brought into a population. Decoders which can't encode return [None], which is the default.
+    fn encode(&self, _solution: &<Self::P as Problem>::Solution) -> Option<Box<[G]>> {
+        None
+    }
+
+    /// Decodes each one of `members` through [decode_value_mut][Self::decode_value_mut], returning their values in order.
+    ///
+    /// Algorithms decode whole populations through it. It's serial by default, while a `Parallel` decoder
+    /// splits the members among threads.
+    fn decode_values_mut(&self, members: &mut [&mut [G]]) -> Vec<<Self::P as Problem>::Value> {
+        members
+            .iter_mut()
+            .map(|member| self.decode_value_mut(member))
+            .collect()
+    }
+
     /// The problem instance which may be decoded.
     fn problem(&self) -> &Self::P;
 }
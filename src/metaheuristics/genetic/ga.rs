@@ -0,0 +1,351 @@
+//! # Genetic Algorithm
+//!
+//! A classic genetic algorithm which works with any gene type, for problems where a direct encoding,
+//! e.g. binary or permutation, is better than [random keys][super::brkga].
+//!
+//! In each generation, the [Params::elites()] best members are kept and the rest of the population is replaced
+//! by children. Each child has two parents picked through a [Selection], whose genes are combined through
+//! a [Crossover] with probability [Params::crossover_rate()], or copied from the first parent otherwise.
+//! Then, the child goes through a [Mutation].
+//!
+//! There are stock implementations of each operator:
+//! - [Selection]: [Tournament], [Roulette] and [Rank].
+//! - [Crossover]: [OnePoint], [Uniform], [Order] (OX) and [PartiallyMapped] (PMX).
+//! - [Mutation]: [Flip], [Reset], [Swap] and [Inversion].
+//!
+//! Members are decoded by a [Decoder] over the same gene type, in parallel if it's wrapped in a `Parallel` decoder. See [parallel].
+//!
+//! The main items here are [Ga] and its [Params].
+//!
+
+use rand::Rng;
+
+use crate::core::{
+    solver::{
+        hook::{Empty, Record},
+        IterHook,
+    },
+    Evaluation, Objective, Problem, Solver, StopCriterion,
+};
+
+use super::{
+    brkga::EmptyHook,
+    parallel,
+    population::{Member, Population},
+    Decoder,
+};
+
+mod crossover;
+pub use crossover::{Crossover, OnePoint, Order, PartiallyMapped, Uniform};
+
+mod mutation;
+pub use mutation::{Flip, Inversion, Mutation, Reset, Swap};
+
+mod params;
+pub use params::{Params, ParamsBuilder, ParamsError};
+
+mod selection;
+pub use selection::{Rank, Roulette, Selection, Tournament};
+
+/// The genetic operators used by a [Ga].
+#[derive(Debug, Clone)]
+pub struct Operators<S, C, M> {
+    /// Picks the parents.
+    pub selection: S,
+    /// Combines the parents into a child.
+    pub crossover: C,
+    /// Changes the child.
+    pub mutation: M,
+}
+
+/// The interface to execute a genetic algorithm over genes of type `G`.
+///
+/// ```
+/// # use optimum::{core::{Evaluation, Objective, Problem}, metaheuristics::genetic::{Decoder, ga::*}};
+/// # use rand::{rngs::StdRng, Rng, SeedableRng};
+/// struct OneMax;
+///
+/// impl Problem for OneMax {
+///     const OBJECTIVE: Objective = Objective::Max;
+///     type Solution = Vec<bool>;
+///     type Value = usize;
+///
+///     fn objective_function(&self, solution: Self::Solution) -> Evaluation<Self> {
+///         let ones = solution.iter().filter(|&&gene| gene).count();
+///         Evaluation::new(solution, ones)
+///     }
+/// }
+///
+/// impl Decoder<bool> for OneMax {
+///     type P = Self;
+///
+///     fn decode(&self, member: &[bool]) -> Vec<bool> {
+///         member.to_vec()
+///     }
+///
+///     fn problem(&self) -> &Self {
+///         self
+///     }
+/// }
+///
+/// let params = Params::builder(20).build().unwrap();
+/// let operators = Operators {
+///     selection: Tournament::new(2),
+///     crossover: OnePoint,
+///     mutation: Flip { probability: 0.05 },
+/// };
+/// let mut ga = Ga::new(&OneMax, StdRng::seed_from_u64(0), params, operators, |rng| {
+///     (0..10).map(|_| rng.gen()).collect()
+/// });
+///
+/// for _ in 0..100 {
+///     ga.evolve();
+/// }
+///
+/// assert!(ga.best().value() >= 8);
+/// ```
+pub struct Ga<'a, G, D: Decoder<G>, R: Rng, S, C, M> {
+    decoder: &'a D,
+    rng: R,
+    operators: Operators<S, C, M>,
+    current: Population<D::P, G>,
+    next: Population<D::P, G>,
+    params: Params,
+    generations: usize,
+    evaluations: usize,
+}
+
+impl<'a, G, D, R, S, C, M> Ga<'a, G, D, R, S, C, M>
+where
+    G: Clone,
+    D: Decoder<G>,
+    R: Rng,
+    S: Selection<D::P, G>,
+    C: Crossover<G>,
+    M: Mutation<G>,
+{
+    /// Creates a new GA instance, which solves the [Problem] defined by the [Decoder].
+    ///
+    /// The initial population is made of the genes generated by `genes`, which is called once for each member.
+    pub fn new<F>(
+        decoder: &'a D,
+        mut rng: R,
+        params: Params,
+        operators: Operators<S, C, M>,
+        mut genes: F,
+    ) -> Self
+    where
+        F: FnMut(&mut R) -> Box<[G]>,
+    {
+        let mut keys = (0..params.population_size())
+            .map(|_| genes(&mut rng))
+            .collect::<Vec<_>>();
+        let values = parallel::decode_all(decoder, &mut keys);
+        let members = keys
+            .into_iter()
+            .zip(values)
+            .map(|(keys, value)| Member::new(keys, value))
+            .collect();
+
+        let mut current = Population::from_members(members);
+        current.sort_for(decoder.problem().objective());
+        let next = current.clone();
+
+        Self {
+            decoder,
+            rng,
+            operators,
+            current,
+            next,
+            params,
+            generations: 0,
+            evaluations: params.population_size(),
+        }
+    }
+
+    /// Replaces the population by the next generation.
+    ///
+    /// 1. Elites are transferred to the next generation.
+    /// 2. The rest of the generation is made of children of selected parents, which are mutated.
+    /// 3. The children are decoded.
+    ///
+    pub fn evolve(&mut self) {
+        let elites = self.params.elites();
+        let (current, next) = (&self.current, &mut self.next);
+
+        for (elite, member) in current.members[..elites]
+            .iter()
+            .zip(&mut next.members[..elites])
+        {
+            member.keys_mut().clone_from_slice(elite.keys());
+            member.set_value(elite.value());
+        }
+
+        for member in &mut next.members[elites..] {
+            let first = self.operators.selection.select(current, &mut self.rng);
+            let second = self.operators.selection.select(current, &mut self.rng);
+            let child = member.keys_mut();
+
+            if self.rng.gen::<f64>() < self.params.crossover_rate() {
+                self.operators.crossover.crossover(
+                    current[first].keys(),
+                    current[second].keys(),
+                    child,
+                    &mut self.rng,
+                );
+            } else {
+                child.clone_from_slice(current[first].keys());
+            }
+
+            self.operators.mutation.mutate(child, &mut self.rng);
+        }
+
+        std::mem::swap(&mut self.current, &mut self.next);
+        self.decode_current();
+        self.generations += 1;
+    }
+
+    /// Decodes the members which changed and sorts the population.
+    fn decode_current(&mut self) {
+        self.evaluations += parallel::decode_changed(self.decoder, &mut self.current.members);
+
+        self.current.sort_for(self.decoder.problem().objective());
+    }
+
+    /// Returns the number of generations since the beginning of the algorithm.
+    pub fn current_generation(&self) -> usize {
+        self.generations
+    }
+
+    /// The population of the most recent generation.
+    pub fn current_population(&self) -> &Population<D::P, G> {
+        &self.current
+    }
+
+    /// Number of members decoded so far, including the initial population.
+    pub fn evaluations(&self) -> usize {
+        self.evaluations
+    }
+
+    /// Returns the best member found.
+    pub fn best(&self) -> &Member<G, <D::P as Problem>::Value> {
+        &self.current[0]
+    }
+}
+
+impl<'a, G, D, R, S, C, M, SC, H> Solver<SC, H> for Ga<'a, G, D, R, S, C, M>
+where
+    G: Clone,
+    D: Decoder<G>,
+    R: Rng,
+    S: Selection<D::P, G>,
+    C: Crossover<G>,
+    M: Mutation<G>,
+    SC: StopCriterion<D::P>,
+    H: GaHook<D::P, G>,
+{
+    type P = D::P;
+
+    fn objective(&self) -> Objective {
+        self.decoder.problem().objective()
+    }
+
+    fn evaluations(&self) -> usize {
+        Ga::evaluations(self)
+    }
+
+    fn iterate(&mut self, _: &mut SC, hook: &mut H) -> Option<Evaluation<Self::P>> {
+        self.evolve();
+        hook.evolved(&self.current);
+
+        let solution = self.decoder.decode(self.best().keys());
+        let evaluation = self.decoder.problem().objective_function(solution);
+
+        Some(evaluation)
+    }
+}
+
+/// A type which can hook into GA-specific events.
+pub trait GaHook<P: Problem, G>: IterHook<P> {
+    /// Called right after an evolution is performed. `population` is the most recent generation.
+    fn evolved(&mut self, _population: &Population<P, G>) {}
+}
+
+impl<P: Problem, G> GaHook<P, G> for Empty {}
+
+impl<P: Problem, G> GaHook<P, G> for EmptyHook {}
+
+impl<P: Problem, G> GaHook<P, G> for Record<P::Value> {}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, seq::SliceRandom, SeedableRng};
+
+    use crate::core::{stop_criterion::IterCriterion, Objective};
+
+    use super::*;
+
+    /// Sorting a permutation by minimizing how far each element is from its place.
+    struct Displacement;
+
+    impl Problem for Displacement {
+        const OBJECTIVE: Objective = Objective::Min;
+
+        type Solution = Vec<usize>;
+
+        type Value = usize;
+
+        fn objective_function(&self, solution: Self::Solution) -> Evaluation<Self> {
+            let value = solution
+                .iter()
+                .enumerate()
+                .map(|(position, &gene)| position.abs_diff(gene))
+                .sum();
+            Evaluation::new(solution, value)
+        }
+    }
+
+    impl Decoder<usize> for Displacement {
+        type P = Self;
+
+        fn decode(&self, member: &[usize]) -> Vec<usize> {
+            member.to_vec()
+        }
+
+        fn problem(&self) -> &Self::P {
+            self
+        }
+    }
+
+    fn solve<C: Crossover<usize>>(crossover: C) -> (usize, usize) {
+        let rng = StdRng::seed_from_u64(0);
+        let params = Params::builder(20).elites(2).build().unwrap();
+        let operators = Operators {
+            selection: Rank::new(1.8),
+            crossover,
+            mutation: Swap { probability: 0.3 },
+        };
+        let mut ga = Ga::new(&Displacement, rng, params, operators, |rng| {
+            let mut genes = (0..10).collect::<Box<[_]>>();
+            genes.shuffle(rng);
+            genes
+        });
+        let initial = ga.best().value();
+
+        ga.solve(&mut IterCriterion::new(100), &mut Empty);
+
+        for member in &ga.current_population().members {
+            let mut genes = member.keys().to_vec();
+            genes.sort_unstable();
+            assert!(genes.into_iter().eq(0..10));
+        }
+        (initial, ga.best().value())
+    }
+
+    #[test]
+    fn permutations_are_improved() {
+        for (initial, last) in [solve(Order), solve(PartiallyMapped)] {
+            assert!(last < initial);
+        }
+    }
+}
@@ -0,0 +1,185 @@
+use rand::Rng;
+
+/// Combines the genes of two parents into a child.
+pub trait Crossover<G> {
+    /// Writes into `child` the combination of the `first` and `second` parents. The three slices have the same length.
+    fn crossover<R: Rng>(&mut self, first: &[G], second: &[G], child: &mut [G], rng: &mut R);
+}
+
+/// Takes the genes before a random cut point from the first parent and the rest from the second.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct OnePoint;
+
+impl<G: Clone> Crossover<G> for OnePoint {
+    fn crossover<R: Rng>(&mut self, first: &[G], second: &[G], child: &mut [G], rng: &mut R) {
+        let cut = rng.gen_range(0..=child.len());
+
+        child[..cut].clone_from_slice(&first[..cut]);
+        child[cut..].clone_from_slice(&second[cut..]);
+    }
+}
+
+/// Takes each gene from the first parent with probability `bias`, or from the second one otherwise.
+#[derive(Debug, Clone, Copy)]
+pub struct Uniform {
+    /// The probability of taking a gene from the first parent.
+    pub bias: f64,
+}
+
+impl Default for Uniform {
+    fn default() -> Self {
+        Self { bias: 0.5 }
+    }
+}
+
+impl<G: Clone> Crossover<G> for Uniform {
+    fn crossover<R: Rng>(&mut self, first: &[G], second: &[G], child: &mut [G], rng: &mut R) {
+        for ((gene, first), second) in child.iter_mut().zip(first).zip(second) {
+            let source = if rng.gen::<f64>() < self.bias {
+                first
+            } else {
+                second
+            };
+            *gene = source.clone();
+        }
+    }
+}
+
+/// Order crossover (OX) for permutations of `0..n`.
+///
+/// A random segment is copied from the first parent and the remaining genes are filled in the order they
+/// appear in the second parent, starting right after the segment.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Order;
+
+impl Crossover<usize> for Order {
+    fn crossover<R: Rng>(
+        &mut self,
+        first: &[usize],
+        second: &[usize],
+        child: &mut [usize],
+        rng: &mut R,
+    ) {
+        let n = child.len();
+        if n == 0 {
+            return;
+        }
+        let (start, end) = segment(n, rng);
+
+        let mut used = vec![false; n];
+        child[start..end].copy_from_slice(&first[start..end]);
+        for &gene in &first[start..end] {
+            used[gene] = true;
+        }
+
+        let mut position = end % n;
+        for offset in 0..n {
+            let gene = second[(end + offset) % n];
+            if !used[gene] {
+                child[position] = gene;
+                position = (position + 1) % n;
+            }
+        }
+    }
+}
+
+/// Partially mapped crossover (PMX) for permutations of `0..n`.
+///
+/// A random segment is copied from the first parent and the other positions keep the genes of the second parent,
+/// following the mapping between the parents' segments whenever a gene would be repeated.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PartiallyMapped;
+
+impl Crossover<usize> for PartiallyMapped {
+    fn crossover<R: Rng>(
+        &mut self,
+        first: &[usize],
+        second: &[usize],
+        child: &mut [usize],
+        rng: &mut R,
+    ) {
+        let n = child.len();
+        if n == 0 {
+            return;
+        }
+        let (start, end) = segment(n, rng);
+
+        // Position of each gene in the first parent's segment, if it's there.
+        let mut mapping = vec![None; n];
+        for position in start..end {
+            mapping[first[position]] = Some(position);
+        }
+
+        child[start..end].copy_from_slice(&first[start..end]);
+        for position in (0..start).chain(end..n) {
+            let mut gene = second[position];
+            while let Some(mapped) = mapping[gene] {
+                gene = second[mapped];
+            }
+            child[position] = gene;
+        }
+    }
+}
+
+/// A random non empty range of positions in `0..n`, which must not be empty.
+fn segment<R: Rng>(n: usize, rng: &mut R) -> (usize, usize) {
+    let start = rng.gen_range(0..n);
+    let end = rng.gen_range(start + 1..=n);
+    (start, end)
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, seq::SliceRandom, SeedableRng};
+
+    use super::*;
+
+    fn is_permutation(genes: &[usize]) -> bool {
+        let mut sorted = genes.to_vec();
+        sorted.sort_unstable();
+        sorted.into_iter().eq(0..genes.len())
+    }
+
+    #[test]
+    fn permutations_are_preserved() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut first = (0..8).collect::<Vec<_>>();
+        let mut second = first.clone();
+        let mut child = vec![0; 8];
+
+        for _ in 0..100 {
+            first.shuffle(&mut rng);
+            second.shuffle(&mut rng);
+
+            Order.crossover(&first, &second, &mut child, &mut rng);
+            assert!(is_permutation(&child));
+
+            PartiallyMapped.crossover(&first, &second, &mut child, &mut rng);
+            assert!(is_permutation(&child));
+        }
+    }
+
+    #[test]
+    fn genes_come_from_the_parents() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let first = [true; 6];
+        let second = [false; 6];
+        let mut child = [false; 6];
+
+        OnePoint.crossover(&first, &second, &mut child, &mut rng);
+        let cut = child.iter().take_while(|&&gene| gene).count();
+        assert!(child[cut..].iter().all(|&gene| !gene));
+
+        Uniform { bias: 1.0 }.crossover(&first, &second, &mut child, &mut rng);
+        assert_eq!(child, first);
+    }
+
+    #[test]
+    fn empty_permutations_are_supported() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut child: [usize; 0] = [];
+
+        Order.crossover(&[], &[], &mut child, &mut rng);
+        PartiallyMapped.crossover(&[], &[], &mut child, &mut rng);
+    }
+}
@@ -0,0 +1,108 @@
+use rand::{
+    distributions::{Distribution, Standard},
+    Rng,
+};
+
+/// Randomly changes the genes of a child.
+pub trait Mutation<G> {
+    /// Changes `genes` in place.
+    fn mutate<R: Rng>(&mut self, genes: &mut [G], rng: &mut R);
+}
+
+/// Flips each gene of a binary encoding with the given `probability`.
+#[derive(Debug, Clone, Copy)]
+pub struct Flip {
+    /// The probability of flipping each gene.
+    pub probability: f64,
+}
+
+impl Mutation<bool> for Flip {
+    fn mutate<R: Rng>(&mut self, genes: &mut [bool], rng: &mut R) {
+        for gene in genes {
+            if rng.gen::<f64>() < self.probability {
+                *gene = !*gene;
+            }
+        }
+    }
+}
+
+/// Replaces each gene by a random one with the given `probability`.
+#[derive(Debug, Clone, Copy)]
+pub struct Reset {
+    /// The probability of replacing each gene.
+    pub probability: f64,
+}
+
+impl<G> Mutation<G> for Reset
+where
+    Standard: Distribution<G>,
+{
+    fn mutate<R: Rng>(&mut self, genes: &mut [G], rng: &mut R) {
+        for gene in genes {
+            if rng.gen::<f64>() < self.probability {
+                *gene = rng.gen();
+            }
+        }
+    }
+}
+
+/// Swaps two random genes with the given `probability`, which keeps permutations valid.
+#[derive(Debug, Clone, Copy)]
+pub struct Swap {
+    /// The probability of swapping genes in a child.
+    pub probability: f64,
+}
+
+impl<G> Mutation<G> for Swap {
+    fn mutate<R: Rng>(&mut self, genes: &mut [G], rng: &mut R) {
+        if genes.len() > 1 && rng.gen::<f64>() < self.probability {
+            let a = rng.gen_range(0..genes.len());
+            let b = rng.gen_range(0..genes.len());
+            genes.swap(a, b);
+        }
+    }
+}
+
+/// Reverses a random segment of genes with the given `probability`, which keeps permutations valid.
+#[derive(Debug, Clone, Copy)]
+pub struct Inversion {
+    /// The probability of reversing a segment of a child.
+    pub probability: f64,
+}
+
+impl<G> Mutation<G> for Inversion {
+    fn mutate<R: Rng>(&mut self, genes: &mut [G], rng: &mut R) {
+        if genes.len() > 1 && rng.gen::<f64>() < self.probability {
+            let start = rng.gen_range(0..genes.len());
+            let end = rng.gen_range(start + 1..=genes.len());
+            genes[start..end].reverse();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+
+    #[test]
+    fn probabilities_are_respected() {
+        let mut rng = StdRng::seed_from_u64(0);
+
+        let mut genes = [false; 5];
+        Flip { probability: 1.0 }.mutate(&mut genes, &mut rng);
+        assert_eq!(genes, [true; 5]);
+
+        let mut genes = [0.5; 5];
+        Reset { probability: 0.0 }.mutate(&mut genes, &mut rng);
+        Swap { probability: 0.0 }.mutate(&mut genes, &mut rng);
+        assert_eq!(genes, [0.5; 5]);
+
+        let mut genes = [0, 1, 2, 3, 4];
+        Inversion { probability: 1.0 }.mutate(&mut genes, &mut rng);
+        let mut sorted = genes;
+        sorted.sort_unstable();
+        assert_eq!(sorted, [0, 1, 2, 3, 4]);
+    }
+}
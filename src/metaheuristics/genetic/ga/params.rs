@@ -0,0 +1,143 @@
+use std::num::NonZeroUsize;
+
+use thiserror::Error;
+
+/// The parameters needed to run the [Ga][super::Ga].
+///
+/// It can only be created through [Params::builder], which validates the combination of values given.
+///
+/// ```
+/// # use optimum::metaheuristics::genetic::ga::Params;
+/// let params = Params::builder(100)
+///     .elites(5)
+///     .crossover_rate(0.8)
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(params.elites(), 5);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Params {
+    population_size: NonZeroUsize,
+    elites: usize,
+    crossover_rate: f64,
+}
+
+impl Params {
+    /// Starts the construction of [Params] for a population of `population_size` members.
+    ///
+    /// The other parameters start with the values below, which may be changed through the [ParamsBuilder]:
+    /// - The best member is kept from one generation to the next.
+    /// - 90% of the children are generated through crossover.
+    pub fn builder(population_size: usize) -> ParamsBuilder {
+        ParamsBuilder {
+            population_size,
+            elites: 1,
+            crossover_rate: 0.9,
+        }
+    }
+
+    /// Number of members in the population.
+    pub fn population_size(&self) -> usize {
+        self.population_size.get()
+    }
+
+    /// Number of best members copied unchanged into the next generation.
+    pub fn elites(&self) -> usize {
+        self.elites
+    }
+
+    /// The probability of generating a child through crossover instead of copying its first parent.
+    pub fn crossover_rate(&self) -> f64 {
+        self.crossover_rate
+    }
+}
+
+/// Builds validated [Params]. See [Params::builder].
+#[derive(Debug, Clone)]
+pub struct ParamsBuilder {
+    population_size: usize,
+    elites: usize,
+    crossover_rate: f64,
+}
+
+impl ParamsBuilder {
+    /// Sets the number of best members copied unchanged into the next generation.
+    pub fn elites(mut self, elites: usize) -> Self {
+        self.elites = elites;
+        self
+    }
+
+    /// Sets the probability of generating a child through crossover, which must be in \[0.0, 1.0\].
+    pub fn crossover_rate(mut self, crossover_rate: f64) -> Self {
+        self.crossover_rate = crossover_rate;
+        self
+    }
+
+    /// Validates the parameters given.
+    pub fn build(self) -> Result<Params, ParamsError> {
+        let population_size =
+            NonZeroUsize::new(self.population_size).ok_or(ParamsError::EmptyPopulation)?;
+
+        // Every generation needs at least one child.
+        if self.elites >= self.population_size {
+            return Err(ParamsError::TooManyElites {
+                elites: self.elites,
+                population_size: self.population_size,
+            });
+        }
+
+        if !(0.0..=1.0).contains(&self.crossover_rate) {
+            return Err(ParamsError::InvalidCrossoverRate(self.crossover_rate));
+        }
+
+        Ok(Params {
+            population_size,
+            elites: self.elites,
+            crossover_rate: self.crossover_rate,
+        })
+    }
+}
+
+/// The reasons why [ParamsBuilder::build] may fail.
+#[derive(Debug, Error, Clone, Copy, PartialEq)]
+pub enum ParamsError {
+    /// The population must have at least one member.
+    #[error("the population must have at least one member")]
+    EmptyPopulation,
+    /// There must be room for at least one child in each generation.
+    #[error("{elites} elites leave no room for children in a population of {population_size}")]
+    TooManyElites {
+        /// Number of elites.
+        elites: usize,
+        /// Number of members in the population.
+        population_size: usize,
+    },
+    /// The crossover rate must be a probability.
+    #[error("the crossover rate must be in [0.0, 1.0], but it's {0}")]
+    InvalidCrossoverRate(f64),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn invalid_combinations_are_rejected() {
+        assert_eq!(
+            Params::builder(0).build().unwrap_err(),
+            ParamsError::EmptyPopulation
+        );
+        assert_eq!(
+            Params::builder(5).elites(5).build().unwrap_err(),
+            ParamsError::TooManyElites {
+                elites: 5,
+                population_size: 5
+            }
+        );
+        assert_eq!(
+            Params::builder(5).crossover_rate(1.5).build().unwrap_err(),
+            ParamsError::InvalidCrossoverRate(1.5)
+        );
+    }
+}
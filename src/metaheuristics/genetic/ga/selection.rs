@@ -0,0 +1,207 @@
+use rand::Rng;
+
+use crate::{core::Problem, metaheuristics::genetic::population::Population};
+
+/// Chooses the members of a [Population] which become parents.
+pub trait Selection<P: Problem, G> {
+    /// Picks the position of a member of `population`, which is sorted from the best member to the worst.
+    fn select<R: Rng>(&mut self, population: &Population<P, G>, rng: &mut R) -> usize;
+}
+
+/// Picks the best among `size` members drawn at random, with replacement.
+///
+/// Larger tournaments favor the best members more.
+#[derive(Debug, Clone, Copy)]
+pub struct Tournament {
+    size: usize,
+}
+
+impl Tournament {
+    /// Creates tournaments among `size` members.
+    ///
+    /// # Panics
+    ///
+    /// If `size` is zero.
+    pub fn new(size: usize) -> Self {
+        assert!(size > 0, "tournaments must have at least one member");
+        Self { size }
+    }
+
+    /// The number of members competing in each tournament.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+}
+
+impl<P: Problem, G> Selection<P, G> for Tournament {
+    fn select<R: Rng>(&mut self, population: &Population<P, G>, rng: &mut R) -> usize {
+        (0..self.size)
+            .map(|_| rng.gen_range(0..population.size()))
+            .min()
+            .expect("tournaments have at least one member")
+    }
+}
+
+/// Picks members with probability proportional to their fitness.
+///
+/// `fitness` maps the value of a member into a non negative number, which must be greater for better members.
+/// When every fitness is zero, members are picked uniformly.
+#[derive(Debug, Clone, Copy)]
+pub struct Roulette<F> {
+    /// The fitness of a member, given its value.
+    pub fitness: F,
+}
+
+impl<P, G, F> Selection<P, G> for Roulette<F>
+where
+    P: Problem,
+    F: FnMut(P::Value) -> f64,
+{
+    fn select<R: Rng>(&mut self, population: &Population<P, G>, rng: &mut R) -> usize {
+        let weights = population
+            .members
+            .iter()
+            .map(|member| (self.fitness)(member.value()))
+            .collect::<Vec<_>>();
+
+        spin(&weights, rng)
+    }
+}
+
+/// Picks members with probability decreasing linearly with their rank.
+///
+/// `pressure` is the expected number of times the best member is picked in a population-sized draw.
+/// It must be in \[1.0, 2.0\]: 1.0 picks uniformly and 2.0 never picks the worst member.
+#[derive(Debug, Clone, Copy)]
+pub struct Rank {
+    pressure: f64,
+}
+
+impl Rank {
+    /// Creates a rank selection with the given `pressure`.
+    ///
+    /// # Panics
+    ///
+    /// If `pressure` isn't in \[1.0, 2.0\].
+    pub fn new(pressure: f64) -> Self {
+        assert!(
+            (1.0..=2.0).contains(&pressure),
+            "the selection pressure must be in [1.0, 2.0]"
+        );
+        Self { pressure }
+    }
+
+    /// The selection pressure, in \[1.0, 2.0\].
+    pub fn pressure(&self) -> f64 {
+        self.pressure
+    }
+}
+
+impl<P: Problem, G> Selection<P, G> for Rank {
+    fn select<R: Rng>(&mut self, population: &Population<P, G>, rng: &mut R) -> usize {
+        let size = population.size();
+        if size == 1 {
+            return 0;
+        }
+
+        let weights = (0..size)
+            .map(|rank| {
+                self.pressure - (2.0 * self.pressure - 2.0) * rank as f64 / (size - 1) as f64
+            })
+            .collect::<Vec<_>>();
+
+        spin(&weights, rng)
+    }
+}
+
+/// Picks a position with probability proportional to its weight.
+fn spin<R: Rng>(weights: &[f64], rng: &mut R) -> usize {
+    let total: f64 = weights.iter().sum();
+    if total <= 0.0 {
+        return rng.gen_range(0..weights.len());
+    }
+
+    let mut choice = rng.gen::<f64>() * total;
+    for (position, weight) in weights.iter().enumerate() {
+        if choice < *weight {
+            return position;
+        }
+        choice -= weight;
+    }
+
+    weights.len() - 1
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use crate::core::{Evaluation, Objective};
+    use crate::metaheuristics::genetic::population::Member;
+
+    use super::*;
+
+    struct Identity;
+
+    impl Problem for Identity {
+        const OBJECTIVE: Objective = Objective::Max;
+
+        type Solution = usize;
+
+        type Value = usize;
+
+        fn objective_function(&self, solution: Self::Solution) -> Evaluation<Self> {
+            Evaluation::new(solution, solution)
+        }
+    }
+
+    fn population() -> Population<Identity, ()> {
+        Population::new(10, |value| Member::new(Box::new([]), value))
+    }
+
+    fn picks(selection: &mut impl Selection<Identity, ()>) -> Vec<usize> {
+        let population = population();
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut picks = vec![0; population.size()];
+        for _ in 0..1000 {
+            picks[selection.select(&population, &mut rng)] += 1;
+        }
+        picks
+    }
+
+    #[test]
+    fn better_members_are_favored() {
+        let tournament = picks(&mut Tournament::new(3));
+        let roulette = picks(&mut Roulette {
+            fitness: |value: usize| value as f64,
+        });
+        let rank = picks(&mut Rank::new(2.0));
+
+        for picks in [tournament, roulette, rank] {
+            assert!(picks[0] > picks[9]);
+        }
+    }
+
+    #[test]
+    fn zero_weights_are_skipped() {
+        let rank = picks(&mut Rank::new(2.0));
+        let roulette = picks(&mut Roulette {
+            fitness: |value: usize| value as f64,
+        });
+
+        assert_eq!(rank[9], 0);
+        assert_eq!(roulette[9], 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn empty_tournaments_are_rejected() {
+        Tournament::new(0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn pressures_out_of_range_are_rejected() {
+        Rank::new(2.5);
+    }
+}
@@ -0,0 +1,383 @@
+//! # NSGA-II
+//!
+//! The Non-dominated Sorting Genetic Algorithm II, for [MultiObjective] problems decoded from random keys.
+//! Any [Decoder] may be reused, as long as its problem is [MultiObjective].
+//!
+//! In each generation, a child is created for each member of the population. Both parents are chosen through
+//! binary tournaments, their keys are combined through uniform crossover and mutated. Then, parents and
+//! children are ranked by [fast non-dominated sorting][non_dominated_sort] and the best fronts survive.
+//! The last front which fits only partially is cut by [crowding distance][crowding_distances],
+//! which keeps the population spread along the Pareto front.
+//!
+//! The main items here are [Nsga2] and its [Params].
+//!
+
+use rand::Rng;
+
+use crate::core::{
+    crowding_distances, dominance,
+    solver::{
+        hook::{Empty, Record},
+        IterHook,
+    },
+    Dominance, Evaluation, MultiObjective, Objective, Problem, Solver, StopCriterion,
+};
+
+use super::{
+    brkga::{Ctx, MemberBuilder},
+    parallel,
+    population::{Member, Population},
+    Decoder, RandomKey,
+};
+
+mod params;
+pub use params::{Params, ParamsBuilder, ParamsError};
+
+/// The interface to execute the NSGA-II algorithm.
+///
+/// The population is kept ordered by rank and, within each front, by decreasing crowding distance.
+/// So, [front][Self::front] is the current approximation of the Pareto front.
+pub struct Nsga2<'a, D: Decoder, R: Rng> {
+    decoder: &'a D,
+    rng: R,
+    population: Population<D::P, RandomKey>,
+    ranks: Vec<usize>,
+    params: Params,
+    generations: usize,
+    evaluations: usize,
+}
+
+impl<'a, D, R> Nsga2<'a, D, R>
+where
+    D: Decoder,
+    D::P: MultiObjective,
+    R: Rng,
+{
+    /// Creates a new NSGA-II instance, which solves the [Problem] defined by the [Decoder].
+    ///
+    /// The initial population is generated through `member_builder`.
+    pub fn new<B: MemberBuilder<D, R>>(
+        decoder: &'a D,
+        mut rng: R,
+        params: Params,
+        mut member_builder: B,
+    ) -> Self {
+        let mut ctx = Ctx {
+            decoder,
+            rng: &mut rng,
+            member_size: params.member_size(),
+        };
+        let members = member_builder.build_population(&mut ctx, params.population_size());
+
+        let mut nsga2 = Self {
+            decoder,
+            rng,
+            population: Population::from_members(Vec::new()),
+            ranks: Vec::new(),
+            params,
+            generations: 0,
+            evaluations: params.population_size(),
+        };
+        nsga2.survive(members);
+        nsga2
+    }
+
+    /// Replaces the population by the best among itself and its children.
+    ///
+    /// 1. Children are generated through binary tournaments, uniform crossover and mutation.
+    /// 2. Children are decoded.
+    /// 3. Parents and children are sorted into fronts and the best ones survive.
+    ///
+    pub fn evolve(&mut self) {
+        let mut keys = (0..self.params.population_size())
+            .map(|_| self.child())
+            .collect::<Vec<_>>();
+
+        let values = parallel::decode_all(self.decoder, &mut keys);
+        self.evaluations += keys.len();
+
+        let mut members = std::mem::take(&mut self.population.members);
+        members.extend(
+            keys.into_iter()
+                .zip(values)
+                .map(|(keys, value)| Member::new(keys, value)),
+        );
+
+        self.survive(members);
+        self.generations += 1;
+    }
+
+    /// Creates the keys of a new child.
+    fn child(&mut self) -> Box<[RandomKey]> {
+        let first = self.tournament();
+        let second = self.tournament();
+        let (first, second) = (&self.population[first], &self.population[second]);
+
+        (0..self.params.member_size())
+            .map(|gene| {
+                if self.rng.gen::<f64>() < self.params.mutation_probability() {
+                    self.rng.gen()
+                } else if self.rng.gen::<f64>() < self.params.crossover_bias() {
+                    first[gene]
+                } else {
+                    second[gene]
+                }
+            })
+            .collect()
+    }
+
+    /// Binary tournament by rank and crowding distance.
+    fn tournament(&mut self) -> usize {
+        let size = self.population.size();
+        let a = self.rng.gen_range(0..size);
+        let b = self.rng.gen_range(0..size);
+
+        // Members are ordered by rank and crowding distance, so the first position is the winner.
+        a.min(b)
+    }
+
+    /// Keeps the best [population_size][Params::population_size] members, in order of rank and crowding distance.
+    fn survive(&mut self, members: Vec<Member<RandomKey, <D::P as Problem>::Value>>) {
+        let size = self.params.population_size();
+        let mut population = Population::from_members(members);
+        let values = population
+            .members
+            .iter()
+            .map(|m| m.value())
+            .collect::<Vec<_>>();
+
+        let mut order = Vec::with_capacity(size);
+        self.ranks.clear();
+
+        for (rank, front) in non_dominated_sort::<D::P>(&values).into_iter().enumerate() {
+            if order.len() == size {
+                break;
+            }
+
+            let front_values = front.iter().map(|&m| values[m]).collect::<Vec<_>>();
+            let mut crowded = front
+                .into_iter()
+                .zip(crowding_distances::<D::P>(&front_values))
+                .collect::<Vec<_>>();
+            crowded.sort_by(|a, b| b.1.total_cmp(&a.1));
+
+            for (member, _) in crowded.into_iter().take(size - order.len()) {
+                order.push(member);
+                self.ranks.push(rank);
+            }
+        }
+
+        population.rearrange(&order);
+        self.population = population;
+    }
+
+    /// Returns the number of generations since the beginning of the algorithm.
+    pub fn current_generation(&self) -> usize {
+        self.generations
+    }
+
+    /// Number of members decoded so far, including the initial population.
+    pub fn evaluations(&self) -> usize {
+        self.evaluations
+    }
+
+    /// The population of the most recent generation, ordered by rank and crowding distance.
+    pub fn current_population(&self) -> &Population<D::P, RandomKey> {
+        &self.population
+    }
+
+    /// The rank of each member of the [current population][Self::current_population]. Rank 0 is the first front.
+    pub fn ranks(&self) -> &[usize] {
+        &self.ranks
+    }
+
+    /// The non-dominated members of the current population.
+    pub fn front(&self) -> &[Member<RandomKey, <D::P as Problem>::Value>] {
+        let size = self.ranks.iter().take_while(|&&rank| rank == 0).count();
+        &self.population.members[..size]
+    }
+}
+
+/// Sorts `values` into fronts by Pareto dominance, returning the positions in each front.
+///
+/// The first front has the values which aren't dominated by any other. Each following front has the values
+/// which are dominated only by values in the fronts before it.
+pub fn non_dominated_sort<P: MultiObjective>(values: &[P::Value]) -> Vec<Vec<usize>> {
+    let mut dominators = vec![0; values.len()];
+    let mut dominated = vec![Vec::new(); values.len()];
+
+    for a in 0..values.len() {
+        for b in (a + 1)..values.len() {
+            match dominance::<P>(values[a], values[b]) {
+                Dominance::Better => {
+                    dominated[a].push(b);
+                    dominators[b] += 1;
+                }
+                Dominance::Worse => {
+                    dominated[b].push(a);
+                    dominators[a] += 1;
+                }
+                Dominance::Equal | Dominance::Incomparable => {}
+            }
+        }
+    }
+
+    let mut front = (0..values.len())
+        .filter(|&value| dominators[value] == 0)
+        .collect::<Vec<_>>();
+    let mut fronts = Vec::new();
+
+    while !front.is_empty() {
+        let mut next = Vec::new();
+        for &a in &front {
+            for &b in &dominated[a] {
+                dominators[b] -= 1;
+                if dominators[b] == 0 {
+                    next.push(b);
+                }
+            }
+        }
+        fronts.push(std::mem::replace(&mut front, next));
+    }
+
+    fronts
+}
+
+impl<'a, D, R, SC, H> Solver<SC, H> for Nsga2<'a, D, R>
+where
+    D: Decoder,
+    D::P: MultiObjective,
+    R: Rng,
+    SC: StopCriterion<D::P>,
+    H: Nsga2Hook<D>,
+{
+    type P = D::P;
+
+    fn objective(&self) -> Objective {
+        self.decoder.problem().objective()
+    }
+
+    fn evaluations(&self) -> usize {
+        Nsga2::evaluations(self)
+    }
+
+    /// Evolves the population and returns the least crowded member of the first front.
+    ///
+    /// As [Solver::solve] keeps a single evaluation, the whole front should be taken through
+    /// [Nsga2::front] or [Nsga2Hook::evolved].
+    fn iterate(&mut self, _: &mut SC, hook: &mut H) -> Option<Evaluation<Self::P>> {
+        self.evolve();
+        hook.evolved(&self.population, &self.ranks);
+
+        let solution = self.decoder.decode(self.population[0].keys());
+        let evaluation = self.decoder.problem().objective_function(solution);
+
+        Some(evaluation)
+    }
+}
+
+/// A type which can hook into NSGA-II-specific events.
+pub trait Nsga2Hook<D: Decoder>: IterHook<D::P> {
+    /// Called right after an evolution is performed. `population` is the most recent generation
+    /// and `ranks` has the rank of each one of its members.
+    fn evolved(&mut self, _population: &Population<D::P, RandomKey>, _ranks: &[usize]) {}
+}
+
+impl<D: Decoder> Nsga2Hook<D> for Empty {}
+
+impl<D: Decoder> Nsga2Hook<D> for Record<<D::P as Problem>::Value> {}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use crate::{
+        core::{stop_criterion::IterCriterion, Objective},
+        metaheuristics::genetic::brkga::RandomMemberBuilder,
+    };
+
+    use super::*;
+
+    /// Minimizes the first key and its complement plus the other keys, so
+    /// the Pareto front is where every key but the first one is zero.
+    struct Tradeoff;
+
+    impl Problem for Tradeoff {
+        const OBJECTIVE: Objective = Objective::Min;
+
+        type Solution = Vec<RandomKey>;
+
+        type Value = [usize; 2];
+
+        fn objective_function(&self, solution: Self::Solution) -> Evaluation<Self> {
+            let first = solution[0];
+            let rest = solution[1..].iter().sum::<f64>();
+            let value = [
+                (first * 1000.0) as usize,
+                ((1.0 - first + rest) * 1000.0) as usize,
+            ];
+            Evaluation::new(solution, value)
+        }
+    }
+
+    impl MultiObjective for Tradeoff {
+        type Component = usize;
+
+        const OBJECTIVES: &'static [Objective] = &[Objective::Min, Objective::Min];
+
+        fn component(value: &Self::Value, objective: usize) -> Self::Component {
+            value[objective]
+        }
+    }
+
+    impl Decoder for Tradeoff {
+        type P = Self;
+
+        fn decode(&self, member: &[RandomKey]) -> Vec<RandomKey> {
+            member.to_vec()
+        }
+
+        fn problem(&self) -> &Self::P {
+            self
+        }
+    }
+
+    #[test]
+    fn fronts_are_sorted_by_dominance() {
+        let values = [[3, 3], [1, 4], [2, 2], [4, 1], [3, 4], [5, 5]];
+
+        let fronts = non_dominated_sort::<Tradeoff>(&values);
+
+        assert_eq!(fronts, [vec![1, 2, 3], vec![0], vec![4], vec![5]]);
+    }
+
+    #[test]
+    fn front_approaches_the_pareto_front() {
+        let rng = StdRng::seed_from_u64(0);
+        let params = Params::builder(20, 4).build().unwrap();
+        let mut nsga2 = Nsga2::new(&Tradeoff, rng, params, RandomMemberBuilder);
+        let distance = |nsga2: &Nsga2<Tradeoff, StdRng>| {
+            let front = nsga2.front();
+            let total = front
+                .iter()
+                .map(|member| member.keys()[1..].iter().sum::<f64>())
+                .sum::<f64>();
+            total / front.len() as f64
+        };
+        let initial = distance(&nsga2);
+
+        nsga2.solve(&mut IterCriterion::new(50), &mut Empty);
+
+        assert!(distance(&nsga2) < initial);
+        assert!(nsga2.ranks().windows(2).all(|pair| pair[0] <= pair[1]));
+        for a in nsga2.front() {
+            for b in nsga2.front() {
+                let dominance = dominance::<Tradeoff>(a.value(), b.value());
+                assert!(matches!(
+                    dominance,
+                    Dominance::Equal | Dominance::Incomparable
+                ));
+            }
+        }
+    }
+}
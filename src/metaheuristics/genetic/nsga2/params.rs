@@ -0,0 +1,158 @@
+use std::num::NonZeroUsize;
+
+use thiserror::Error;
+
+/// The parameters needed to run the [Nsga2][super::Nsga2] algorithm.
+///
+/// It can only be created through [Params::builder], which validates the combination of values given.
+///
+/// ```
+/// # use optimum::metaheuristics::genetic::nsga2::Params;
+/// let params = Params::builder(100, 20)
+///     .mutation_probability(0.1)
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(params.crossover_bias(), 0.5);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Params {
+    population_size: NonZeroUsize,
+    member_size: NonZeroUsize,
+    crossover_bias: f64,
+    mutation_probability: f64,
+}
+
+impl Params {
+    /// Starts the construction of [Params] for a population of `population_size` members with `member_size` keys each.
+    ///
+    /// The other parameters start with the values below, which may be changed through the [ParamsBuilder]:
+    /// - Each key of a child comes from either parent with the same probability.
+    /// - Each key of a child is mutated with probability `1 / member_size`.
+    pub fn builder(population_size: usize, member_size: usize) -> ParamsBuilder {
+        ParamsBuilder {
+            population_size,
+            member_size,
+            crossover_bias: 0.5,
+            mutation_probability: None,
+        }
+    }
+
+    /// Number of members in the population.
+    pub fn population_size(&self) -> usize {
+        self.population_size.get()
+    }
+
+    /// Number of keys in a member.
+    pub fn member_size(&self) -> usize {
+        self.member_size.get()
+    }
+
+    /// The probability of choosing a key from the first parent. It's a value in \[0.0, 1.0\].
+    pub fn crossover_bias(&self) -> f64 {
+        self.crossover_bias
+    }
+
+    /// The probability of replacing each key of a child by a random one. It's a value in \[0.0, 1.0\].
+    pub fn mutation_probability(&self) -> f64 {
+        self.mutation_probability
+    }
+}
+
+/// Builds validated [Params]. See [Params::builder].
+#[derive(Debug, Clone)]
+pub struct ParamsBuilder {
+    population_size: usize,
+    member_size: usize,
+    crossover_bias: f64,
+    mutation_probability: Option<f64>,
+}
+
+impl ParamsBuilder {
+    /// Sets the probability of choosing a key from the first parent, which must be in \[0.0, 1.0\].
+    pub fn crossover_bias(mut self, crossover_bias: f64) -> Self {
+        self.crossover_bias = crossover_bias;
+        self
+    }
+
+    /// Sets the probability of mutating each key of a child, which must be in \[0.0, 1.0\].
+    pub fn mutation_probability(mut self, probability: f64) -> Self {
+        self.mutation_probability = Some(probability);
+        self
+    }
+
+    /// Validates the parameters given.
+    pub fn build(self) -> Result<Params, ParamsError> {
+        let population_size =
+            NonZeroUsize::new(self.population_size).ok_or(ParamsError::EmptyPopulation)?;
+        let member_size = NonZeroUsize::new(self.member_size).ok_or(ParamsError::EmptyMember)?;
+
+        if !(0.0..=1.0).contains(&self.crossover_bias) {
+            return Err(ParamsError::InvalidCrossoverBias(self.crossover_bias));
+        }
+
+        let mutation_probability = self
+            .mutation_probability
+            .unwrap_or(1.0 / self.member_size as f64);
+        if !(0.0..=1.0).contains(&mutation_probability) {
+            return Err(ParamsError::InvalidMutationProbability(
+                mutation_probability,
+            ));
+        }
+
+        Ok(Params {
+            population_size,
+            member_size,
+            crossover_bias: self.crossover_bias,
+            mutation_probability,
+        })
+    }
+}
+
+/// The reasons why [ParamsBuilder::build] may reject the parameters given.
+#[derive(Debug, Clone, Copy, PartialEq, Error)]
+pub enum ParamsError {
+    /// The population size is zero.
+    #[error("the population must have at least one member")]
+    EmptyPopulation,
+    /// The member size is zero.
+    #[error("a member must have at least one key")]
+    EmptyMember,
+    /// The crossover bias must be a probability.
+    #[error("the crossover bias must be in [0.0, 1.0], but it's {0}")]
+    InvalidCrossoverBias(f64),
+    /// The mutation probability must be a probability.
+    #[error("the mutation probability must be in [0.0, 1.0], but it's {0}")]
+    InvalidMutationProbability(f64),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn invalid_combinations_are_rejected() {
+        assert_eq!(
+            Params::builder(0, 5).build().unwrap_err(),
+            ParamsError::EmptyPopulation
+        );
+        assert_eq!(
+            Params::builder(10, 0).build().unwrap_err(),
+            ParamsError::EmptyMember
+        );
+        assert_eq!(
+            Params::builder(10, 5)
+                .mutation_probability(-0.1)
+                .build()
+                .unwrap_err(),
+            ParamsError::InvalidMutationProbability(-0.1)
+        );
+        assert_eq!(
+            Params::builder(10, 4)
+                .build()
+                .unwrap()
+                .mutation_probability(),
+            0.25
+        );
+    }
+}
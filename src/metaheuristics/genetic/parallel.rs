@@ -0,0 +1,141 @@
+//! Support for the optional parallel decoding of populations.
+//!
+//! Genetic algorithms decode their members through [Decoder::decode_values_mut], which is serial by default.
+//! With the `parallel` feature, wrapping a decoder in a `Parallel` decodes them across `rayon`'s thread pool
+//! instead. Only the wrapped decoder must be [Sync] and its [Value][crate::core::Problem::Value]s [Send],
+//! so enabling the feature doesn't change what's required from any other decoder.
+//!
+//! Decoders which need scratch space may keep it in a [Scratch][crate::components::scratch::Scratch]
+//! instead of a [RefCell][std::cell::RefCell], so they can be wrapped.
+//!
+//! The number of threads is the one of `rayon`'s global pool, which may be configured through
+//! `rayon::ThreadPoolBuilder::build_global` or by running the solver inside `ThreadPool::install`.
+
+use crate::core::Problem;
+
+use super::{population::Member, Decoder};
+
+#[cfg(feature = "parallel")]
+pub use self::rayon_decoder::Parallel;
+
+#[cfg(feature = "parallel")]
+mod rayon_decoder {
+    use rayon::prelude::*;
+
+    use crate::core::Problem;
+
+    use super::super::Decoder;
+
+    /// Decodes members across `rayon`'s thread pool, forwarding everything else to the decoder it wraps.
+    ///
+    /// Only available with the `parallel` feature.
+    ///
+    /// ```
+    /// # use rand::SeedableRng;
+    /// # use optimum::{core::{Evaluation, Objective, Problem}, metaheuristics::genetic::{brkga::{Brkga, Params, RandomMemberBuilder}, parallel::Parallel, Decoder, RandomKey}};
+    /// # struct KeySum;
+    /// # impl Problem for KeySum {
+    /// #     const OBJECTIVE: Objective = Objective::Min;
+    /// #     type Solution = ();
+    /// #     type Value = usize;
+    /// #     fn objective_function(&self, _: ()) -> Evaluation<Self> { Evaluation::new((), 0) }
+    /// # }
+    /// # impl Decoder for KeySum {
+    /// #     type P = Self;
+    /// #     fn decode(&self, _: &[RandomKey]) {}
+    /// #     fn problem(&self) -> &Self { self }
+    /// # }
+    /// let decoder = Parallel::new(KeySum);
+    /// let params = Params::builder(10, 5).build().unwrap();
+    /// let mut brkga = Brkga::new(&decoder, rand_pcg::Pcg64::seed_from_u64(0), params, RandomMemberBuilder);
+    ///
+    /// brkga.evolve();
+    /// ```
+    #[derive(Debug, Clone, Copy, Default)]
+    pub struct Parallel<D>(pub D);
+
+    impl<D> Parallel<D> {
+        /// Wraps `decoder` so members are decoded in parallel.
+        pub fn new(decoder: D) -> Self {
+            Self(decoder)
+        }
+
+        /// Unwraps the decoder.
+        pub fn into_inner(self) -> D {
+            self.0
+        }
+    }
+
+    impl<G, D> Decoder<G> for Parallel<D>
+    where
+        G: Send,
+        D: Decoder<G> + Sync,
+        <D::P as Problem>::Value: Send,
+    {
+        type P = D::P;
+
+        fn decode(&self, member: &[G]) -> <Self::P as Problem>::Solution {
+            self.0.decode(member)
+        }
+
+        fn decode_value(&self, member: &[G]) -> <Self::P as Problem>::Value {
+            self.0.decode_value(member)
+        }
+
+        fn decode_value_mut(&self, member: &mut [G]) -> <Self::P as Problem>::Value {
+            self.0.decode_value_mut(member)
+        }
+
+        fn encode(&self, solution: &<Self::P as Problem>::Solution) -> Option<Box<[G]>> {
+            self.0.encode(solution)
+        }
+
+        fn decode_values_mut(&self, members: &mut [&mut [G]]) -> Vec<<Self::P as Problem>::Value> {
+            members
+                .par_iter_mut()
+                .map(|member| self.0.decode_value_mut(member))
+                .collect()
+        }
+
+        fn problem(&self) -> &Self::P {
+            self.0.problem()
+        }
+    }
+}
+
+/// Decodes every one of `keys` at once, returning their values in order.
+pub(crate) fn decode_all<G, D, K>(decoder: &D, keys: &mut [K]) -> Vec<<D::P as Problem>::Value>
+where
+    D: Decoder<G>,
+    K: AsMut<[G]>,
+{
+    let mut keys = keys.iter_mut().map(AsMut::as_mut).collect::<Vec<_>>();
+    decoder.decode_values_mut(&mut keys)
+}
+
+/// Decodes at once the members whose keys [changed][Member::is_changed], setting their values.
+///
+/// Returns the number of members decoded.
+pub(crate) fn decode_changed<G, D>(
+    decoder: &D,
+    members: &mut [Member<G, <D::P as Problem>::Value>],
+) -> usize
+where
+    D: Decoder<G>,
+{
+    let mut keys = members
+        .iter_mut()
+        .filter(|member| member.is_changed())
+        .map(Member::keys_mut)
+        .collect::<Vec<_>>();
+    let values = decoder.decode_values_mut(&mut keys);
+    let decoded = values.len();
+
+    members
+        .iter_mut()
+        .filter(|member| member.is_changed())
+        .zip(values)
+        .for_each(|(member, value)| member.set_value(value));
+
+    decoded
+}
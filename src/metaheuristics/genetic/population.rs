@@ -35,6 +35,11 @@ impl<P: Problem, G> Population<P, G> {
     {
         let members = (0..size).map(member_builder).collect::<Vec<_>>();
 
+        Self::from_members(members)
+    }
+
+    /// Creates a new [Population] with the given members
+    pub fn from_members(members: Vec<Member<G, P::Value>>) -> Self {
         let mut population = Self {
             members,
             _p: PhantomData,
@@ -43,14 +48,36 @@ impl<P: Problem, G> Population<P, G> {
         population
     }
 
-    /// Sorts the population, from the best members to the worst.
+    /// Sorts the population, from the best members to the worst according to [Problem::OBJECTIVE].
     pub fn sort(&mut self) {
-        match P::OBJECTIVE {
+        self.sort_for(P::OBJECTIVE)
+    }
+
+    /// Sorts the population, from the best members to the worst in the `objective` direction.
+    pub fn sort_for(&mut self, objective: Objective) {
+        match objective {
             Objective::Min => self.members.sort_unstable(),
             Objective::Max => self.members.sort_unstable_by(|a, b| b.cmp(a)),
         }
     }
 
+    /// Keeps only the members at `positions`, in that order, for orders other than by value.
+    ///
+    /// # Panics
+    ///
+    /// If a position is repeated or out of bounds.
+    pub(crate) fn rearrange(&mut self, positions: &[usize]) {
+        let mut members = std::mem::take(&mut self.members)
+            .into_iter()
+            .map(Some)
+            .collect::<Vec<_>>();
+
+        self.members = positions
+            .iter()
+            .map(|&position| members[position].take().expect("positions are distinct"))
+            .collect();
+    }
+
     /// Number of members of `self`
     pub fn size(&self) -> usize {
         self.members.len()
@@ -1,21 +1,94 @@
 use std::ops::{Index, IndexMut};
 
 /// A slice of genes which represents solutions to a problem.
+///
+/// It keeps track of changes in its genes, so algorithms know which members need to be decoded again.
+/// Any mutable access to the genes (through [keys_mut][Member::keys_mut] or [IndexMut]) marks the member
+/// as [changed][Member::is_changed] until a new value is [set][Member::set_value].
+///
+/// Its fields used to be public, which would let the genes change unnoticed. Members are now built through
+/// [new][Member::new], read through [keys][Member::keys] and [value][Member::value], and taken apart through
+/// [into_parts][Member::into_parts].
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Member<G, V> {
-    /// The slice of genes
-    pub keys: Box<[G]>,
-    /// The value obtained by decoding the `keys`.
-    pub value: V,
+    keys: Box<[G]>,
+    value: V,
+    changed: bool,
 }
 
 impl<G, V> Member<G, V> {
+    /// Creates a new member whose `value` was obtained by decoding its `keys`.
+    pub fn new(keys: Box<[G]>, value: V) -> Self {
+        Self {
+            keys,
+            value,
+            changed: false,
+        }
+    }
+
+    /// Creates a new member from its former public fields.
+    #[deprecated(
+        note = "use `Member::new`, the fields are private so changes to the genes are tracked"
+    )]
+    pub fn from_parts(keys: Box<[G]>, value: V) -> Self {
+        Self::new(keys, value)
+    }
+
+    /// Takes the member apart into its genes and value, which may be outdated if it [is changed][Self::is_changed].
+    pub fn into_parts(self) -> (Box<[G]>, V) {
+        (self.keys, self.value)
+    }
+
     /// The number of genes in the member.
     ///
     #[allow(clippy::len_without_is_empty)]
     pub fn len(&self) -> usize {
         self.keys.len()
     }
+
+    /// The slice of genes.
+    pub fn keys(&self) -> &[G] {
+        &self.keys
+    }
+
+    /// The slice of genes, which is marked as changed.
+    pub fn keys_mut(&mut self) -> &mut [G] {
+        self.changed = true;
+        &mut self.keys
+    }
+
+    /// The value obtained by decoding the genes.
+    ///
+    /// It may be outdated if the member [is changed][Self::is_changed].
+    pub fn value(&self) -> V
+    where
+        V: Copy,
+    {
+        self.value
+    }
+
+    /// Sets the value obtained by decoding the current genes.
+    pub fn set_value(&mut self, value: V) {
+        self.value = value;
+        self.changed = false;
+    }
+
+    /// True when the genes were changed since the last time the value was set.
+    pub fn is_changed(&self) -> bool {
+        self.changed
+    }
+
+    /// Turns `self` into a copy of `other`, including its value and whether it's changed.
+    pub fn copy_from(&mut self, other: &Self)
+    where
+        G: Copy,
+        V: Copy,
+    {
+        self.keys.copy_from_slice(&other.keys);
+        self.value = other.value;
+        self.changed = other.changed;
+    }
 }
 
 impl<G, V: Ord> Ord for Member<G, V> {
@@ -48,6 +121,35 @@ impl<G, V> Index<usize> for Member<G, V> {
 
 impl<G, V> IndexMut<usize> for Member<G, V> {
     fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        self.changed = true;
         &mut self.keys[index]
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Member;
+
+    #[test]
+    fn changes_are_tracked() {
+        let mut member = Member::new(vec![0.1, 0.2].into_boxed_slice(), 3);
+        assert!(!member.is_changed());
+
+        member[0] = 0.5;
+        assert!(member.is_changed());
+
+        member.set_value(7);
+        assert!(!member.is_changed());
+
+        member.keys_mut().fill(0.0);
+        assert!(member.is_changed());
+
+        let mut copy = Member::new(vec![0.0; 2].into_boxed_slice(), 0);
+        copy.copy_from(&Member::new(vec![0.3, 0.4].into_boxed_slice(), 1));
+        assert_eq!(
+            (copy.keys(), copy.value(), copy.is_changed()),
+            (&[0.3, 0.4][..], 1, false)
+        );
+        assert_eq!(copy.into_parts(), (vec![0.3, 0.4].into_boxed_slice(), 1));
+    }
+}
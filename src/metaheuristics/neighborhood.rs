@@ -1,6 +1,6 @@
 #![allow(missing_docs)]
 
-use crate::core::{compare_values, Comparison, Evaluation, Problem};
+use crate::core::{Comparison, Delta, Evaluation, Problem};
 
 /// Represents the surround of the current solution. Acts like an iterator of [Move]s to neighbors inside it.
 pub trait Neighborhood<P: Problem> {
@@ -9,6 +9,14 @@ pub trait Neighborhood<P: Problem> {
     fn next_neighbor(&mut self, problem: &P, evaluation: &Evaluation<P>) -> Option<Self::Move>;
 
     fn solution_changed(&mut self, _: &Evaluation<P>) {}
+
+    /// Number of neighbors evaluated so far, through their [value][Move::value] or [delta][Move::delta].
+    ///
+    /// Neighborhoods which only generate moves don't evaluate them, so it's zero by default. Explorers count
+    /// the moves they compare, so evaluation-based stop criteria work for the local searches using them.
+    fn evaluations(&self) -> usize {
+        0
+    }
 }
 
 pub trait Move<P: Problem> {
@@ -16,9 +24,41 @@ pub trait Move<P: Problem> {
 
     fn value(&self, problem: &P, evaluation: &Evaluation<P>) -> P::Value;
 
+    /// The difference between the value of the neighbor and the one of `evaluation`, when it can be computed
+    /// without building the neighbor. Explorers compare moves through it when it's available.
+    ///
+    /// Moves which implement it usually implement [value][Self::value] through [Delta::apply].
+    /// In debug builds, local searches check it against a full evaluation of the neighbor,
+    /// see [delta_matches][Self::delta_matches].
+    fn delta(&self, _problem: &P, _evaluation: &Evaluation<P>) -> Option<Delta<P::Value>> {
+        None
+    }
+
+    /// Tells if `delta` is the difference from `from` to `to`, usually as `Delta::between(from, to) == delta`.
+    ///
+    /// In debug builds, local searches check the [delta][Self::delta] of each move through it. Values can't
+    /// always be subtracted, so only the direction of `delta` is checked by default; moves whose values can
+    /// should override it to check its size too.
+    fn delta_matches(
+        &self,
+        problem: &P,
+        delta: Delta<P::Value>,
+        from: P::Value,
+        to: P::Value,
+    ) -> bool {
+        problem.objective().compare(to, from) == delta.comparison(problem.objective())
+    }
+
+    /// Compares the neighbor with `evaluation`, following the problem's [objective][Problem::objective].
     fn compare(&self, problem: &P, evaluation: &Evaluation<P>) -> Comparison {
+        if let Some(delta) = self.delta(problem, evaluation) {
+            return delta.comparison(problem.objective());
+        }
+
         let neighbor_value = self.value(problem, evaluation);
-        compare_values::<P>(neighbor_value, evaluation.value())
+        problem
+            .objective()
+            .compare(neighbor_value, evaluation.value())
     }
 }
 
@@ -1,11 +1,12 @@
 use std::marker::PhantomData;
 
-use crate::core::{compare_values, Comparison, Evaluation, Problem};
+use crate::core::{Comparison, Evaluation, Problem};
 
 use super::{Move, Neighborhood};
 
 pub struct FirstImprovement<P, N> {
     neighborhood: N,
+    evaluations: usize,
     _p: PhantomData<P>,
 }
 
@@ -13,6 +14,7 @@ impl<P: Problem, N: Neighborhood<P>> FirstImprovement<P, N> {
     pub fn new(neighborhood: N) -> Self {
         Self {
             neighborhood,
+            evaluations: 0,
             _p: PhantomData,
         }
     }
@@ -29,6 +31,7 @@ where
         loop {
             let r#move = self.neighborhood.next_neighbor(problem, evaluation)?;
 
+            self.evaluations += 1;
             if r#move.compare(problem, evaluation) == Comparison::Better {
                 return Some(r#move);
             }
@@ -38,6 +41,10 @@ where
     fn solution_changed(&mut self, evaluation: &Evaluation<P>) {
         self.neighborhood.solution_changed(evaluation)
     }
+
+    fn evaluations(&self) -> usize {
+        self.evaluations + self.neighborhood.evaluations()
+    }
 }
 
 impl<P: Problem, N: Neighborhood<P>> From<N> for FirstImprovement<P, N> {
@@ -48,6 +55,7 @@ impl<P: Problem, N: Neighborhood<P>> From<N> for FirstImprovement<P, N> {
 
 pub struct BestImprovement<P, N> {
     neighborhood: N,
+    evaluations: usize,
     _p: PhantomData<P>,
 }
 
@@ -55,6 +63,7 @@ impl<P: Problem, N: Neighborhood<P>> BestImprovement<P, N> {
     pub fn new(neighborhood: N) -> Self {
         Self {
             neighborhood,
+            evaluations: 0,
             _p: PhantomData,
         }
     }
@@ -71,10 +80,22 @@ where
         let mut best = self.neighborhood.next_neighbor(problem, evaluation)?;
         let e = evaluation;
         let p = problem;
+        let mut best_delta = best.delta(p, e);
+        let objective = p.objective();
+        self.evaluations += 1;
 
         while let Some(r#move) = self.neighborhood.next_neighbor(problem, evaluation) {
-            if compare_values::<P>(r#move.value(p, e), best.value(p, e)) == Comparison::Better {
-                best = r#move
+            self.evaluations += 1;
+            // Deltas are compared without building the neighbors, when both moves have them.
+            let delta = r#move.delta(p, e);
+            let comparison = match (delta, best_delta) {
+                (Some(delta), Some(best_delta)) => delta.compare(best_delta, objective),
+                _ => objective.compare(r#move.value(p, e), best.value(p, e)),
+            };
+
+            if comparison == Comparison::Better {
+                best = r#move;
+                best_delta = delta;
             }
         }
 
@@ -91,6 +112,10 @@ where
     fn solution_changed(&mut self, evaluation: &Evaluation<P>) {
         self.neighborhood.solution_changed(evaluation)
     }
+
+    fn evaluations(&self) -> usize {
+        self.evaluations + self.neighborhood.evaluations()
+    }
 }
 
 impl<P: Problem, N: Neighborhood<P>> From<N> for BestImprovement<P, N> {
@@ -138,4 +163,8 @@ where
         self.current = 0;
         self.neighborhood.solution_changed(evaluation)
     }
+
+    fn evaluations(&self) -> usize {
+        self.neighborhood.evaluations()
+    }
 }
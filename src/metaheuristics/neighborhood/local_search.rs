@@ -1,6 +1,6 @@
-use std::marker::PhantomData;
+use std::{marker::PhantomData, time::Instant};
 
-use crate::core::{Evaluation, Problem, StopCriterion};
+use crate::core::{stop_criterion::Context, Comparison, Evaluation, Problem, StopCriterion};
 
 use super::{
     explorers::{BestImprovement, FirstImprovement},
@@ -70,16 +70,79 @@ where
     P: Problem,
     N: Neighborhood<P>,
 {
+    stop_criterion.started();
+    let start = Instant::now();
+    let mut iteration = 0;
+    // Moves may be applied without improving, e.g. by [HillWalking], so the best value is kept apart.
+    let mut best = evaluation.value();
+    // Evaluations of the neighborhood already reported to the stop criterion.
+    let mut reported = 0;
+
     while !stop_criterion.should_stop() {
+        iteration += 1;
+        let mut improved = false;
+        // Applying a move evaluates the neighbor it leads to.
+        let mut applied = 0;
+
         if let Some(r#move) = neighborhood.next_neighbor(problem, &evaluation) {
+            #[cfg(debug_assertions)]
+            let expected = r#move.delta(problem, &evaluation).map(|delta| {
+                let value = r#move.value(problem, &evaluation);
+                (
+                    value,
+                    r#move.delta_matches(problem, delta, evaluation.value(), value),
+                )
+            });
+
             evaluation = r#move.apply(problem, evaluation);
+            applied = 1;
+
+            #[cfg(debug_assertions)]
+            if let Some(expected) = expected {
+                evaluation = check_delta(problem, evaluation, expected);
+            }
+
             neighborhood.solution_changed(&evaluation);
+
+            improved = problem.objective().compare(evaluation.value(), best) == Comparison::Better;
+            if improved {
+                best = evaluation.value();
+            }
         }
-        stop_criterion.update(evaluation.value());
+
+        let evaluations = neighborhood.evaluations();
+        stop_criterion.evaluated(evaluations - reported + applied);
+        reported = evaluations;
+
+        stop_criterion.update(Context {
+            candidate: &evaluation,
+            best,
+            improved,
+            elapsed: start.elapsed(),
+            iteration,
+        });
     }
     evaluation
 }
 
+/// Evaluates the neighbor reached by a move from scratch, checking that its value
+/// matches the move's [value][Move::value], which must [match][Move::delta_matches] its [delta][Move::delta].
+#[cfg(debug_assertions)]
+fn check_delta<P: Problem>(
+    problem: &P,
+    neighbor: Evaluation<P>,
+    (value, delta_matches): (P::Value, bool),
+) -> Evaluation<P> {
+    let neighbor = problem.objective_function(neighbor.into_solution());
+
+    assert!(
+        neighbor.value() == value && delta_matches,
+        "the move's delta doesn't match a full evaluation of the neighbor"
+    );
+
+    neighbor
+}
+
 macro_rules! impl_local_search_for_adapters {
     ($($struct:tt),*) => {
         $(impl<P: Problem, N: Neighborhood<P>> LocalSearch<P> for $struct<P, N> {
@@ -96,3 +159,143 @@ macro_rules! impl_local_search_for_adapters {
 }
 
 impl_local_search_for_adapters! { HillWalking, HillClimbing, SteepestAscent }
+
+#[cfg(test)]
+mod tests {
+    use crate::core::{stop_criterion::EvaluationCriterion, Delta};
+
+    use super::*;
+
+    /// Always moves to a worse neighbor.
+    struct Worsen;
+
+    impl Neighborhood<()> for Worsen {
+        type Move = Worsen;
+
+        fn next_neighbor(&mut self, _: &(), _: &Evaluation<()>) -> Option<Self::Move> {
+            Some(Worsen)
+        }
+    }
+
+    impl Move<()> for Worsen {
+        fn apply(&self, problem: &(), evaluation: Evaluation<()>) -> Evaluation<()> {
+            Evaluation::new((), self.value(problem, &evaluation))
+        }
+
+        fn value(&self, _: &(), evaluation: &Evaluation<()>) -> usize {
+            evaluation.value() + 1
+        }
+    }
+
+    /// Always moves to a better neighbor.
+    struct Improve;
+
+    impl Neighborhood<()> for Improve {
+        type Move = Improve;
+
+        fn next_neighbor(&mut self, _: &(), _: &Evaluation<()>) -> Option<Self::Move> {
+            Some(Improve)
+        }
+    }
+
+    impl Move<()> for Improve {
+        fn apply(&self, problem: &(), evaluation: Evaluation<()>) -> Evaluation<()> {
+            Evaluation::new((), self.value(problem, &evaluation))
+        }
+
+        fn value(&self, _: &(), evaluation: &Evaluation<()>) -> usize {
+            evaluation.value() - 1
+        }
+    }
+
+    /// Improves by two, but claims to improve by one.
+    struct Understate;
+
+    impl Neighborhood<()> for Understate {
+        type Move = Understate;
+
+        fn next_neighbor(&mut self, _: &(), _: &Evaluation<()>) -> Option<Self::Move> {
+            Some(Understate)
+        }
+    }
+
+    impl Move<()> for Understate {
+        fn apply(&self, problem: &(), evaluation: Evaluation<()>) -> Evaluation<()> {
+            Evaluation::new((), self.value(problem, &evaluation))
+        }
+
+        fn value(&self, _: &(), evaluation: &Evaluation<()>) -> usize {
+            evaluation.value() - 2
+        }
+
+        fn delta(&self, _: &(), _: &Evaluation<()>) -> Option<Delta<usize>> {
+            Some(Delta::Decrease(1))
+        }
+
+        fn delta_matches(&self, _: &(), delta: Delta<usize>, from: usize, to: usize) -> bool {
+            Delta::between(from, to) == delta
+        }
+    }
+
+    /// Keeps what it's told in each iteration, stopping after three of them.
+    #[derive(Default)]
+    struct Contexts(Vec<(usize, usize, bool)>);
+
+    impl StopCriterion<()> for Contexts {
+        fn progress(&self) -> f64 {
+            self.0.len() as f64 / 3.0
+        }
+
+        fn update(&mut self, context: Context<'_, ()>) {
+            self.0
+                .push((context.candidate.value(), context.best, context.improved));
+        }
+
+        fn current_iter(&self) -> usize {
+            self.0.len()
+        }
+    }
+
+    #[test]
+    fn worsening_moves_are_not_improvements() {
+        let mut stop_criterion = Contexts::default();
+
+        let evaluation = HillWalking::new(Worsen).reach_local_optima(
+            &(),
+            Evaluation::new((), 10),
+            &mut stop_criterion,
+        );
+
+        assert_eq!(evaluation.value(), 13);
+        assert_eq!(
+            stop_criterion.0,
+            [(11, 10, false), (12, 10, false), (13, 10, false)]
+        );
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "the move's delta doesn't match a full evaluation of the neighbor")]
+    fn deltas_of_the_wrong_size_are_caught() {
+        HillWalking::new(Understate).reach_local_optima(
+            &(),
+            Evaluation::new((), 10),
+            &mut EvaluationCriterion::new(6),
+        );
+    }
+
+    #[test]
+    fn evaluated_neighbors_are_reported() {
+        let mut stop_criterion = EvaluationCriterion::new(6);
+
+        let evaluation = HillClimbing::new(Improve).reach_local_optima(
+            &(),
+            Evaluation::new((), 10),
+            &mut stop_criterion,
+        );
+
+        // Each iteration compares a neighbor and then applies the move to it.
+        assert_eq!(evaluation.value(), 7);
+        assert_eq!(stop_criterion.current_iter(), 3);
+    }
+}